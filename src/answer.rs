@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+/// The answer to one part of a problem, keeping track of the type the solver produced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    I64(i64),
+    U16(u16),
+    U64(u64),
+    Usize(usize),
+    U128(u128),
+    Text(String),
+    /// There is no answer, for the reason given, e.g. an exit that cannot be reached.
+    Unsolvable(String),
+}
+
+impl Answer {
+    /// The name of the type the solver produced, e.g. `u128` or `string`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::I64(_) => "i64",
+            Answer::U16(_) => "u16",
+            Answer::U64(_) => "u64",
+            Answer::Usize(_) => "usize",
+            Answer::U128(_) => "u128",
+            Answer::Text(_) => "string",
+            Answer::Unsolvable(_) => "none",
+        }
+    }
+
+    /// The answer of type `type_name`, as [`Answer::type_name`] names it, written as `text`.
+    pub fn from_typed(type_name: &str, text: &str) -> Option<Self> {
+        Some(match type_name {
+            "i64" => Answer::I64(text.parse().ok()?),
+            "u16" => Answer::U16(text.parse().ok()?),
+            "u64" => Answer::U64(text.parse().ok()?),
+            "usize" => Answer::Usize(text.parse().ok()?),
+            "u128" => Answer::U128(text.parse().ok()?),
+            "string" => Answer::Text(text.to_owned()),
+            "none" => Answer::Unsolvable(text.strip_prefix("no answer: ")?.to_owned()),
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::I64(value) => value.fmt(f),
+            Answer::U16(value) => value.fmt(f),
+            Answer::U64(value) => value.fmt(f),
+            Answer::Usize(value) => value.fmt(f),
+            Answer::U128(value) => value.fmt(f),
+            Answer::Text(value) => value.fmt(f),
+            Answer::Unsolvable(reason) => write!(f, "no answer: {reason}"),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::I64(value)
+    }
+}
+
+impl From<u16> for Answer {
+    fn from(value: u16) -> Self {
+        Answer::U16(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::U64(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Usize(value)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::U128(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// A solver that may find no answer gives the reason as error.
+impl<T: Into<Answer>> From<Result<T, String>> for Answer {
+    fn from(value: Result<T, String>) -> Self {
+        value.map_or_else(Answer::Unsolvable, Into::into)
+    }
+}
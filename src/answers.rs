@@ -0,0 +1,123 @@
+use crate::{
+    Answer, Part, input,
+    toml::{self, Document, FileError, TomlError, Value},
+};
+use std::path::{Path, PathBuf};
+
+/// The recorded answers, in a TOML file with one `[problemN]` table holding `part1` to `part3`.
+#[derive(Debug, Default)]
+pub struct Answers {
+    document: Document,
+}
+
+/// How an answer compares with the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Value },
+    Missing,
+}
+
+pub fn default_path(set: Option<&str>) -> PathBuf {
+    input::inputs_dir(set).join("answers.toml")
+}
+
+fn table_name(problem: u8) -> String {
+    format!("problem{problem}")
+}
+
+fn key(part: Part) -> String {
+    format!("part{part}")
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, TomlError> {
+        let document = toml::parse(text)?;
+        for (name, table) in &document {
+            let known_problem = name
+                .strip_prefix("problem")
+                .and_then(|id| id.parse::<u8>().ok())
+                .is_some_and(|id| table_name(id) == *name);
+            if !known_problem {
+                return Err(TomlError {
+                    line: toml::line_of(text, name),
+                    message: format!("unexpected table `{name}`, expected `[problemN]`"),
+                });
+            }
+            if let Some(key) = table
+                .keys()
+                .find(|&key| !Part::ALL.iter().any(|&part| self::key(part) == *key))
+            {
+                return Err(TomlError {
+                    line: toml::line_of(text, key),
+                    message: format!("unexpected key `{key}`, expected part1, part2 or part3"),
+                });
+            }
+        }
+        Ok(Self { document })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, FileError> {
+        toml::read_file(path, Self::parse)
+    }
+
+    pub fn expected(&self, problem: u8, part: Part) -> Option<&Value> {
+        self.document.get(&table_name(problem))?.get(&key(part))
+    }
+
+    pub fn check(&self, problem: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.expected(problem, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected.to_string() == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+[problem11]
+part1 = 9047685997827
+part2 = \"4iWAbo%6\"
+
+[problem16]
+part3 = 46999332815858431946223
+";
+
+    #[test]
+    fn verdicts() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(
+            answers.check(11, Part::One, &Answer::U64(9047685997827)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(11, Part::Two, &Answer::from("4iWAbo%6")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(16, Part::Three, &Answer::U128(46999332815858431946222)),
+            Verdict::Fail {
+                expected: Value::Integer(46999332815858431946223)
+            }
+        );
+        assert_eq!(
+            answers.check(11, Part::Three, &Answer::U64(2366)),
+            Verdict::Missing
+        );
+    }
+
+    #[test]
+    fn unknown_entries_are_rejected() {
+        assert_eq!(
+            Answers::parse("[problem1]\npart4 = 1\n").unwrap_err().line,
+            2
+        );
+        assert_eq!(Answers::parse("\n[problem01]\n").unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,214 @@
+//! Timing of the parsing and of each part separately, over repeated runs.
+
+use crate::{
+    ParseError, Part, Solution,
+    toml::{self, Document, FileError, TomlError, Value},
+};
+use std::{
+    fmt::Display,
+    fs,
+    hint::black_box,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// A step of a solution that is timed on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Stage {
+    /// The name of the stage in a baseline file.
+    fn key(self) -> String {
+        match self {
+            Stage::Parse => "parse".to_owned(),
+            Stage::Part(part) => format!("part{part}"),
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarizes a non-empty set of samples. The median of an even count is the lower one.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        Self {
+            min: samples[0],
+            median: samples[(samples.len() - 1) / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// The statistics of each timed stage, in the order they ran.
+pub type Timings = Vec<(Stage, Stats)>;
+
+/// Times `runs` parses of `input`, then `runs` solves of each of the `parts`.
+pub fn measure<S: Solution>(
+    input: &str,
+    params: &S::Params,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Timings, ParseError> {
+    assert!(runs > 0, "at least one run is needed");
+    let data = S::parse(input)?;
+    let mut timings = vec![(
+        Stage::Parse,
+        Stats::from_samples(samples(runs, || S::parse(black_box(input)))),
+    )];
+    for &part in parts {
+        let stats = Stats::from_samples(samples(runs, || S::solve(black_box(&data), params, part)));
+        timings.push((Stage::Part(part), stats));
+    }
+    Ok(timings)
+}
+
+fn samples<T>(runs: usize, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+/// Writes `duration` with an adapted unit and four significant digits at most, e.g. `12.35ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    let (value, unit) = match nanos {
+        0..1_000 => return format!("{nanos}ns"),
+        1_000..1_000_000 => (nanos as f64 / 1e3, "µs"),
+        1_000_000..1_000_000_000 => (nanos as f64 / 1e6, "ms"),
+        _ => (nanos as f64 / 1e9, "s"),
+    };
+    let decimals = if value < 10.0 {
+        3
+    } else if value < 100.0 {
+        2
+    } else {
+        1
+    };
+    format!("{value:.decimals$}{unit}")
+}
+
+/// The median time of each stage from an earlier run, in a TOML file with one `[problemN]`
+/// table mapping `parse` and `part1` to `part3` to nanoseconds.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    document: Document,
+}
+
+pub fn default_baseline_path(set: Option<&str>) -> PathBuf {
+    crate::input::inputs_dir(set).join("baseline.toml")
+}
+
+fn table_name(problem: u8) -> String {
+    format!("problem{problem}")
+}
+
+impl Baseline {
+    pub fn parse(text: &str) -> Result<Self, TomlError> {
+        let document = toml::parse(text)?;
+        if let Some((name, key)) = document
+            .iter()
+            .flat_map(|(name, table)| table.iter().map(move |(key, value)| (name, key, value)))
+            .find_map(|(name, key, value)| match value {
+                Value::Integer(nanos) if u64::try_from(*nanos).is_ok() => None,
+                _ => Some((name, key)),
+            })
+        {
+            return Err(TomlError {
+                line: toml::line_of(text, key),
+                message: format!("`{key}` in `[{name}]` is not a number of nanoseconds"),
+            });
+        }
+        Ok(Self { document })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, FileError> {
+        toml::read_file(path, Self::parse)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, toml::format(&self.document))
+    }
+
+    pub fn median(&self, problem: u8, stage: Stage) -> Option<Duration> {
+        match self.document.get(&table_name(problem))?.get(&stage.key())? {
+            Value::Integer(nanos) => Some(Duration::from_nanos(*nanos as u64)),
+            Value::String(_) => None,
+        }
+    }
+
+    /// Replaces the recorded median of `stage` with the one of `stats`.
+    pub fn record(&mut self, problem: u8, stage: Stage, stats: &Stats) {
+        self.document
+            .entry(table_name(problem))
+            .or_default()
+            .insert(stage.key(), Value::Integer(stats.median.as_nanos() as i128));
+    }
+}
+
+/// How much slower `median` is than `baseline`, in percent; negative when it is faster.
+pub fn change_percent(median: Duration, baseline: Duration) -> f64 {
+    (median.as_secs_f64() / baseline.as_secs_f64().max(1e-9) - 1.0) * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            Stats::from_samples(vec![ms(5), ms(1), ms(9), ms(3)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(9)
+            }
+        );
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(845)), "845ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.500ms");
+        assert_eq!(format_duration(Duration::from_millis(250_000)), "250.0s");
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let stats = Stats::from_samples(vec![Duration::from_micros(1_200)]);
+        let mut baseline = Baseline::default();
+        baseline.record(18, Stage::Part(Part::One), &stats);
+        let baseline = Baseline::parse(&toml::format(&baseline.document)).unwrap();
+        assert_eq!(
+            baseline.median(18, Stage::Part(Part::One)),
+            Some(Duration::from_micros(1_200))
+        );
+        assert_eq!(baseline.median(18, Stage::Parse), None);
+        assert!(Baseline::parse("[problem1]\nparse = \"fast\"\n").is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use std::{env, str::FromStr};
+
+/// The command line arguments that have not been consumed by a command yet.
+pub struct Args {
+    remaining: Vec<String>,
+}
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self {
+            remaining: env::args().skip(1).collect(),
+        }
+    }
+
+    /// Removes `--name value` or `--name=value` and returns the value.
+    pub fn option(&mut self, name: &str) -> Result<Option<String>, String> {
+        let prefix = format!("{name}=");
+        let Some(index) = self
+            .remaining
+            .iter()
+            .position(|arg| arg == name || arg.starts_with(&prefix))
+        else {
+            return Ok(None);
+        };
+        let arg = self.remaining.remove(index);
+        if let Some(value) = arg.strip_prefix(&prefix) {
+            Ok(Some(value.to_owned()))
+        } else if index < self.remaining.len() {
+            Ok(Some(self.remaining.remove(index)))
+        } else {
+            Err(format!("missing value for `{name}`"))
+        }
+    }
+
+    /// Removes every occurrence of `--name value` or `--name=value` and returns the values.
+    pub fn repeated_option(&mut self, name: &str) -> Result<Vec<String>, String> {
+        let mut values = vec![];
+        while let Some(value) = self.option(name)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Removes `--name` and tells whether it was present.
+    pub fn flag(&mut self, name: &str) -> bool {
+        let before = self.remaining.len();
+        self.remaining.retain(|arg| arg != name);
+        self.remaining.len() < before
+    }
+
+    pub fn parsed_option<T>(&mut self, name: &str) -> Result<Option<T>, String>
+    where
+        T: FromStr,
+        T::Err: ToString,
+    {
+        self.option(name)?
+            .map(|value| value.parse().map_err(|err: T::Err| err.to_string()))
+            .transpose()
+    }
+
+    /// Removes the first argument that is not an option. Options must be consumed beforehand.
+    pub fn positional(&mut self) -> Option<String> {
+        let index = self
+            .remaining
+            .iter()
+            .position(|arg| !arg.starts_with("--"))?;
+        Some(self.remaining.remove(index))
+    }
+
+    pub fn finish(self) -> Result<(), String> {
+        match self.remaining.first() {
+            None => Ok(()),
+            Some(arg) => Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    CliError,
+    args::Args,
+    params::{self, ParamArgs},
+    run::{lookup, read_in, take_set},
+    table,
+};
+use codyssi_2025::{
+    Part,
+    bench::{self, Baseline, Timings},
+    input,
+    registry::{Problem, ProblemParams},
+};
+use std::path::PathBuf;
+
+const DEFAULT_RUNS: usize = 10;
+const DEFAULT_TOLERANCE: f64 = 10.0;
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let part = args.parsed_option::<Part>("--part")?;
+    let runs = args
+        .parsed_option::<usize>("--runs")?
+        .unwrap_or(DEFAULT_RUNS);
+    let input = args.option("--input")?.map(PathBuf::from);
+    let set = take_set(&mut args)?;
+    let baseline_path = args
+        .option("--baseline")?
+        .map_or_else(|| bench::default_baseline_path(set.name), PathBuf::from);
+    let tolerance = args
+        .parsed_option::<f64>("--tolerance")?
+        .unwrap_or(DEFAULT_TOLERANCE);
+    let save = args.flag("--save");
+    let param_args = ParamArgs::take(&mut args)?;
+    let target = args.positional().ok_or("missing problem number")?;
+    args.finish()?;
+
+    if runs == 0 {
+        return Err("`--runs` must be at least 1".into());
+    }
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut baseline = if baseline_path.exists() {
+        Baseline::load(&baseline_path).map_err(|err| CliError::Failed(format!("error: {err}")))?
+    } else {
+        Baseline::default()
+    };
+
+    let timings: Vec<(u8, Result<Timings, String>)> = if target == "all" {
+        let overrides = param_args.overrides(set, None)?;
+        let dirs = [
+            input.unwrap_or_else(|| input::inputs_dir(set.name)),
+            input::fetched_dir(set.name),
+        ];
+        let mut timings = vec![];
+        for problem in set.problems {
+            let params = params::resolve(problem, &overrides)?;
+            let problem_timings = read_in(&dirs, problem)
+                .and_then(|input| measure(problem, &input, &params, &parts, runs));
+            timings.push((problem.id, problem_timings));
+        }
+        timings
+    } else {
+        let problem = lookup(set, &target)?;
+        let params = params::resolve(problem, &param_args.overrides(set, Some(problem.id))?)?;
+        let input = input::read(set.name, problem.id, input.as_deref())
+            .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+        vec![(problem.id, measure(problem, &input, &params, &parts, runs))]
+    };
+
+    let mut regressions = 0;
+    let mut rows = vec![];
+    for (id, timings) in &timings {
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(message) => {
+                rows.push(vec![id.to_string(), message.clone()]);
+                continue;
+            }
+        };
+        for (stage, stats) in timings {
+            let change = match baseline.median(*id, *stage) {
+                None => String::new(),
+                Some(recorded) => {
+                    let change = bench::change_percent(stats.median, recorded);
+                    if change > tolerance {
+                        regressions += 1;
+                        format!("{change:+.1}% REGRESSION")
+                    } else {
+                        format!("{change:+.1}%")
+                    }
+                }
+            };
+            rows.push(vec![
+                id.to_string(),
+                stage.to_string(),
+                bench::format_duration(stats.min),
+                bench::format_duration(stats.median),
+                bench::format_duration(stats.max),
+                change,
+            ]);
+            if save {
+                baseline.record(*id, *stage, stats);
+            }
+        }
+    }
+
+    table::print(
+        ["problem", "stage", "min", "median", "max", "vs baseline"]
+            .map(str::to_owned)
+            .to_vec(),
+        &rows,
+    );
+
+    if save {
+        baseline.save(&baseline_path).map_err(|err| {
+            CliError::Failed(format!(
+                "error: cannot write baseline {}: {err}",
+                baseline_path.display()
+            ))
+        })?;
+        println!("\nBaseline saved to {}", baseline_path.display());
+    }
+
+    if regressions == 0 {
+        Ok(())
+    } else {
+        Err(CliError::Failed(format!(
+            "error: {regressions} stage(s) are more than {tolerance}% slower than the baseline"
+        )))
+    }
+}
+
+fn measure(
+    problem: &Problem,
+    input: &str,
+    params: &ProblemParams,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Timings, String> {
+    problem
+        .bench(input, params, parts, runs)
+        .map_err(|err| format!("parse error at line {}, column {}", err.line, err.column))
+}
@@ -0,0 +1,27 @@
+use crate::{CliError, args::Args};
+use codyssi_2025::cache::{self, Cache};
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let action = args
+        .positional()
+        .ok_or("missing cache command, expected `clear`")?;
+    args.finish()?;
+
+    match action.as_str() {
+        "clear" => {
+            let cache = Cache::new(&cache::default_dir());
+            let count = cache.clear().map_err(|err| {
+                CliError::Failed(format!(
+                    "error: cannot clear {}: {err}",
+                    cache.dir().display()
+                ))
+            })?;
+            eprintln!(
+                "Removed {count} cached answers from {}",
+                cache.dir().display()
+            );
+            Ok(())
+        }
+        _ => Err(format!("unknown cache command `{action}`, expected `clear`").into()),
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    CliError,
+    args::Args,
+    run::{lookup, take_set},
+};
+use codyssi_2025::{input, remote::Site};
+use std::fs;
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let force = args.flag("--force");
+    let set = take_set(&mut args)?;
+    let target = args.positional().ok_or("missing problem number")?;
+    args.finish()?;
+
+    let problem = lookup(set, &target)?;
+    let path = input::fetched_path(set.name, problem.id);
+    if path.exists() && !force {
+        return Err(CliError::Failed(format!(
+            "error: {} already exists, use `--force` to overwrite it",
+            path.display()
+        )));
+    }
+
+    let site = Site::from_env().map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let input = site
+        .fetch_input(problem.id)
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let written = match path.parent() {
+        Some(dir) => fs::create_dir_all(dir).and_then(|()| fs::write(&path, input)),
+        None => fs::write(&path, input),
+    };
+    written.map_err(|err| {
+        CliError::Failed(format!("error: cannot write {}: {err}", path.display()))
+    })?;
+    eprintln!(
+        "Saved the input of problem {} to {}",
+        problem.id,
+        path.display()
+    );
+    Ok(())
+}
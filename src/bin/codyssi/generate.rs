@@ -0,0 +1,57 @@
+use crate::{
+    CliError,
+    args::Args,
+    run::{lookup, take_set},
+};
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const DEFAULT_SIZE: usize = 50;
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let seed = args.parsed_option::<u64>("--seed")?;
+    let size = args
+        .parsed_option::<usize>("--size")?
+        .unwrap_or(DEFAULT_SIZE);
+    let output = args.option("--output")?.map(PathBuf::from);
+    let force = args.flag("--force");
+    let set = take_set(&mut args)?;
+    let target = args.positional().ok_or("missing problem number")?;
+    args.finish()?;
+
+    let problem = lookup(set, &target)?;
+    let seed = seed.unwrap_or_else(|| {
+        let seed = time_seed();
+        eprintln!("Generating with seed {seed}");
+        seed
+    });
+    write_output(output, &problem.generate(seed, size), force)
+}
+
+/// Writes an input to stdout, or to `output`, which is only overwritten with `force`.
+pub fn write_output(output: Option<PathBuf>, input: &str, force: bool) -> Result<(), CliError> {
+    match output {
+        None => print!("{input}"),
+        Some(path) => {
+            if path.exists() && !force {
+                return Err(CliError::Failed(format!(
+                    "error: {} already exists, use `--force` to overwrite it",
+                    path.display()
+                )));
+            }
+            fs::write(&path, input).map_err(|err| {
+                CliError::Failed(format!("error: cannot write {}: {err}", path.display()))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn time_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
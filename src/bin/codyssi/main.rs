@@ -0,0 +1,146 @@
+mod args;
+mod bench;
+mod cache;
+mod fetch;
+mod generate;
+mod new;
+mod normalize;
+mod output;
+mod params;
+mod pool;
+mod repl;
+mod run;
+mod submit;
+mod table;
+mod verify;
+
+use args::Args;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: codyssi <command> [options]
+
+Commands:
+  run <problem|all>   Solve a problem, or every problem
+  verify [problem]    Compare the answers with the recorded ones
+  bench <problem|all> Time the parsing and each part over repeated runs
+  params [problem]    List the parameters and their values
+  cache clear         Remove the cached answers
+  generate <problem>  Write a random input in the format of the problem
+  normalize <problem> Write an input back in the canonical format of the problem
+  fetch <problem>     Download the input of a problem from the website
+  submit <problem> <part>
+                      Solve a part and submit the answer to the website
+  repl <problem>      Parse the input once, then solve parts and show solver internals
+  new <problem>       Add a problem to the crate, with a stub solution and example tests
+
+Options for every command but `cache`:
+  --set <name>        Work on another challenge set than this crate's, added with `new --set`
+
+Options for `run`:
+  --part <1|2|3>      Only solve this part
+  --input <path>      Input file (`-` for stdin), inputs directory with `all`, or directory of
+                      input files to solve each of them
+  --format <format>   Output format: text (default), answer, json or csv
+  --param <name=val>  Override a parameter, as `N.name=val` with `all` (repeatable)
+  --config <path>     Parameters file (default: params.toml in the inputs directory)
+  --jobs <n>          With `all` or a directory of inputs, solve the parts on n threads
+  --timeout <secs>    With `--jobs`, give up on a part after this long (default: 60)
+  --trace <format>    Write the steps of the solver to stderr, as text or json (JSON lines)
+  --trace-kinds <k,k> With `--trace`, only write these kinds of steps
+  --no-cache          Solve every part, instead of taking the answers solved before
+  --stream            Solve while reading the input, in constant memory (problems 4, 6 and 8)
+
+Options for `verify`:
+  --answers <path>    Answers file (default: answers.toml in the inputs directory)
+  --input <dir>       Inputs directory
+
+Options for `bench`:
+  --part <1|2|3>      Only time this part
+  --runs <n>          Number of runs of each stage (default: 10)
+  --input <path>      Input file (`-` for stdin), or inputs directory with `all`
+  --baseline <path>   Baseline file (default: baseline.toml in the inputs directory)
+  --save              Record the medians of this run in the baseline
+  --tolerance <pct>   Slowdown flagged as a regression (default: 10)
+  --param, --config   As for `run`
+
+Options for `params`:
+  --param, --config   As for `run`
+
+Options for `generate`:
+  --seed <n>          Seed of the input (default: from the clock, printed on stderr)
+  --size <n>          Rough number of records (default: 50)
+  --output <path>     Output file (default: stdout)
+  --force             Overwrite an existing output file
+
+Options for `normalize`:
+  --input <path>      Input file (`-` for stdin)
+  --output, --force   As for `generate`
+
+Options for `fetch`:
+  --force             Download the input again, over the saved one
+
+Options for `submit`:
+  --input <path>      Input file (`-` for stdin)
+  --param, --config   As for `run`
+
+Options for `repl`:
+  --input <path>      Input file (default: the saved input of the problem)
+  --param, --config   Starting parameters, as for `run`
+
+Options for `new`:
+  --set <name>        Add it to another challenge set than this crate's, in `src/set_<name>`,
+                      creating the set if needed
+  --root <dir>        Crate to add it to (default: this one)";
+
+/// How a command failed: either it was misused, or it could not complete.
+pub enum CliError {
+    Usage(String),
+    Failed(String),
+}
+
+impl From<String> for CliError {
+    fn from(message: String) -> Self {
+        CliError::Usage(message)
+    }
+}
+
+impl From<&str> for CliError {
+    fn from(message: &str) -> Self {
+        CliError::Usage(message.to_owned())
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args = Args::from_env();
+    let result = match args.positional().as_deref() {
+        Some("run") => run::command(args),
+        Some("verify") => verify::command(args),
+        Some("bench") => bench::command(args),
+        Some("params") => params::command(args),
+        Some("cache") => cache::command(args),
+        Some("generate") => generate::command(args),
+        Some("normalize") => normalize::command(args),
+        Some("fetch") => fetch::command(args),
+        Some("submit") => submit::command(args),
+        Some("repl") => repl::command(args),
+        Some("new") => new::command(args),
+        Some("help") | None => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(command) => Err(format!("unknown command `{command}`").into()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(CliError::Usage(message)) => {
+            eprintln!("error: {message}");
+            eprintln!("Run `codyssi help` for usage.");
+            ExitCode::from(2)
+        }
+        Err(CliError::Failed(message)) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,551 @@
+use crate::{CliError, args::Args};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The module of a new problem, with `{id}` standing for its number and `{set}` for the
+/// declaration of its challenge set, if it is not this crate's own.
+const MODULE_TEMPLATE: &str = r#"use crate::{Answer, ParseError, Solution, generate::Rng, parse::Source};
+
+pub struct Problem{id};
+
+impl Solution for Problem{id} {
+    const PROBLEM: u8 = {id};
+{set}
+    type Data<'a> = Data;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+type Data = Vec<u64>;
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem{id}::PROBLEM, input);
+    source
+        .lines(input)
+        .map(|line| source.parse(line, "an integer"))
+        .collect()
+}
+
+fn print_input(data: &Data) -> String {
+    data.iter().map(|value| format!("{value}\n")).collect()
+}
+
+/// Writes `size` lines of one integer each.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{}\n", rng.int(0..=99)))
+        .collect()
+}
+
+fn solve_part1(_: &Data) -> u64 {
+    0
+}
+
+fn solve_part2(_: &Data) -> u64 {
+    0
+}
+
+fn solve_part3(_: &Data) -> u64 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "the example is not filled in yet"]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 0);
+    }
+
+    #[test]
+    #[ignore = "the example is not filled in yet"]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 0);
+    }
+
+    #[test]
+    #[ignore = "the example is not filled in yet"]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 0);
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
+"#;
+
+/// The binary of a new problem, with `{imports}` standing for the problem and `run`.
+const BIN_TEMPLATE: &str = "\
+use {crate}::{{imports}};
+
+fn main() {
+    run::<Problem{id}>();
+}
+";
+
+/// The registry of a new challenge set, with its first problem.
+const SET_TEMPLATE: &str = "\
+//! The problems of the `{set}` challenge set, registered as in [`crate::registry`] and found by
+//! [`crate::registry::set`].
+
+pub mod problem{id};
+
+use crate::registry::Problem;
+
+pub static PROBLEMS: [Problem; 1] = [
+    Problem::new::<problem{id}::Problem{id}>(),
+];
+";
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let set = args.option("--set")?;
+    let root = args
+        .option("--root")?
+        .map_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")), PathBuf::from);
+    let id = args
+        .positional()
+        .ok_or("missing problem number")?
+        .parse::<u8>()
+        .map_err(|err| format!("invalid problem number: {err}"))?;
+    args.finish()?;
+
+    let layout = Layout::new(&root, set.as_deref())?;
+    for path in scaffold(&layout, id).map_err(CliError::Failed)? {
+        eprintln!("Created {}", path.display());
+    }
+    Ok(())
+}
+
+/// Where the files of the problems of a challenge set go.
+///
+/// The problems of the crate's own set are at its root, as `crate::problemN`, registered in
+/// [`codyssi_2025::registry::PROBLEMS`]. Those of another set go in a `set_<name>` module,
+/// which has its own `PROBLEMS`, found by name by [`codyssi_2025::registry::set`].
+struct Layout {
+    root: PathBuf,
+    /// The name of the set, for another set than the crate's own.
+    set: Option<String>,
+    /// The module of the set, for another set than the crate's own.
+    module: Option<String>,
+}
+
+impl Layout {
+    fn new(root: &Path, set: Option<&str>) -> Result<Self, String> {
+        if let Some(set) = set {
+            let valid = !set.is_empty()
+                && set
+                    .bytes()
+                    .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_');
+            if !valid {
+                return Err(format!(
+                    "invalid set name `{set}`, expected lowercase letters, digits and `_`"
+                ));
+            }
+        }
+        Ok(Self {
+            root: root.to_owned(),
+            set: set.map(str::to_owned),
+            module: set.map(|set| format!("set_{set}")),
+        })
+    }
+
+    fn src(&self) -> PathBuf {
+        self.root.join("src")
+    }
+
+    fn module_path(&self, id: u8) -> PathBuf {
+        match &self.module {
+            None => self.src().join(format!("problem{id}.rs")),
+            Some(module) => self.src().join(module).join(format!("problem{id}.rs")),
+        }
+    }
+
+    fn bin_path(&self, id: u8) -> PathBuf {
+        let name = match &self.module {
+            None => format!("problem{id}"),
+            Some(module) => format!("{module}_problem{id}"),
+        };
+        self.src().join("bin").join(name).join("main.rs")
+    }
+
+    /// The file declaring the modules of the problems.
+    fn declarations_path(&self) -> PathBuf {
+        match &self.module {
+            None => self.src().join("lib.rs"),
+            Some(module) => self.src().join(format!("{module}.rs")),
+        }
+    }
+}
+
+/// Creates the module, the binary and the example tests of problem `id`, and registers it.
+/// Nothing is written when a file of the problem exists already, or it is registered already.
+///
+/// Returns the paths of the created files.
+fn scaffold(layout: &Layout, id: u8) -> Result<Vec<PathBuf>, String> {
+    let module_path = layout.module_path(id);
+    let bin_path = layout.bin_path(id);
+    for path in [&module_path, &bin_path] {
+        if path.exists() {
+            return Err(format!("error: {} already exists", path.display()));
+        }
+    }
+
+    let crate_name = env!("CARGO_PKG_NAME").replace('-', "_");
+    let module = format!("problem{id}");
+    let set_declaration = match &layout.set {
+        None => String::new(),
+        Some(set) => format!("\n    const SET: Option<&'static str> = Some(\"{set}\");\n"),
+    };
+    let module_in_crate = match &layout.module {
+        None => module.clone(),
+        Some(set) => format!("{set}::{module}"),
+    };
+    // In the order rustfmt puts them.
+    let mut imports = [format!("{module_in_crate}::Problem{id}"), "run".to_owned()];
+    imports.sort();
+    let bin = BIN_TEMPLATE
+        .replace("{crate}", &crate_name)
+        .replace("{imports}", &imports.join(", "))
+        .replace("{id}", &id.to_string());
+
+    let mut edits = vec![];
+    let declarations_path = layout.declarations_path();
+    match &layout.module {
+        None => {
+            let lib = read(&declarations_path)?;
+            edits.push((declarations_path, declare_module(&lib, &module)?));
+            let registry_path = layout.src().join("registry.rs");
+            edits.push((registry_path.clone(), register(&read(&registry_path)?, id)?));
+        }
+        Some(_) if declarations_path.exists() => {
+            let declarations = declare_module(&read(&declarations_path)?, &module)?;
+            edits.push((declarations_path, register_in_set(&declarations, id)?));
+        }
+        Some(set_module) => {
+            let lib_path = layout.src().join("lib.rs");
+            edits.push((
+                lib_path.clone(),
+                declare_module(&read(&lib_path)?, set_module)?,
+            ));
+            let registry_path = layout.src().join("registry.rs");
+            let set = layout.set.as_deref().unwrap_or_default();
+            edits.push((
+                registry_path.clone(),
+                register_set(&read(&registry_path)?, set)?,
+            ));
+            let declarations = SET_TEMPLATE
+                .replace("{set}", layout.set.as_deref().unwrap_or_default())
+                .replace("{id}", &id.to_string());
+            edits.push((declarations_path, declarations));
+        }
+    }
+
+    let module = MODULE_TEMPLATE
+        .replace("{id}", &id.to_string())
+        .replace("{set}", &set_declaration);
+    let created = vec![module_path, bin_path];
+    let files = created
+        .iter()
+        .cloned()
+        .zip([module, bin])
+        .chain(edits)
+        .collect();
+    write_all(files)?;
+    Ok(created)
+}
+
+/// Writes each of `files` next to its path, then renames them all into place, so that an edit
+/// that cannot be made or a file that cannot be written leaves the crate as it was.
+fn write_all(files: Vec<(PathBuf, String)>) -> Result<(), String> {
+    let staged: Vec<(PathBuf, PathBuf)> = files
+        .iter()
+        .map(|(path, _)| {
+            let mut name = path.file_name().unwrap_or_default().to_owned();
+            name.push(".new");
+            (path.with_file_name(name), path.clone())
+        })
+        .collect();
+    for (index, ((staging, _), (_, contents))) in staged.iter().zip(&files).enumerate() {
+        if let Err(err) = write(staging, contents) {
+            for (written, _) in &staged[..index] {
+                let _ = fs::remove_file(written);
+            }
+            return Err(err);
+        }
+    }
+    for (staging, path) in &staged {
+        fs::rename(staging, path)
+            .map_err(|err| format!("error: cannot move {} into place: {err}", staging.display()))?;
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("error: cannot read {}: {err}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    let written = match path.parent() {
+        Some(dir) => fs::create_dir_all(dir).and_then(|()| fs::write(path, contents)),
+        None => fs::write(path, contents),
+    };
+    written.map_err(|err| format!("error: cannot write {}: {err}", path.display()))
+}
+
+/// Inserts `line` among the lines of `text` that `key` recognizes, which are in increasing order
+/// of their keys, after the last one with a lower key than `new_key`.
+fn insert_sorted<K: Ord>(
+    text: &str,
+    key: impl Fn(&str) -> Option<K>,
+    new_key: K,
+    line: &str,
+) -> Result<String, String> {
+    let keyed: Vec<(usize, K)> = text
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, key(line)?)))
+        .collect();
+    if keyed.iter().any(|(_, key)| *key == new_key) {
+        return Err(format!("error: `{}` is there already", line.trim()));
+    }
+    let index = match keyed.iter().rev().find(|(_, key)| *key < new_key) {
+        Some((index, _)) => index + 1,
+        None => keyed
+            .first()
+            .map(|(index, _)| *index)
+            .ok_or_else(|| format!("error: found no place for `{}`", line.trim()))?,
+    };
+    let mut lines: Vec<&str> = text.lines().collect();
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The number of a problem in a line of the registry, between `prefix` and `suffix`.
+fn problem_number(line: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// Adds `pub mod <module>;` to the module declarations of `text`, which rustfmt sorts by name.
+fn declare_module(text: &str, module: &str) -> Result<String, String> {
+    let name = |line: &str| {
+        let line = line.strip_prefix("pub ").unwrap_or(line);
+        Some(line.strip_prefix("mod ")?.strip_suffix(';')?.to_owned())
+    };
+    insert_sorted(text, name, module.to_owned(), &format!("pub mod {module};"))
+}
+
+/// Adds problem `id` to the imports and the `PROBLEMS` of the crate's registry.
+fn register(text: &str, id: u8) -> Result<String, String> {
+    let text = insert_sorted(
+        text,
+        |line| {
+            let (module, name) = line.trim().strip_suffix(',')?.split_once("::")?;
+            let id = module.strip_prefix("problem")?.parse::<u8>().ok()?;
+            (name == format!("Problem{id}")).then_some(id)
+        },
+        id,
+        &format!("    problem{id}::Problem{id},"),
+    )?;
+    let text = insert_sorted(
+        &text,
+        |line| {
+            problem_number(line, "Problem::new::<Problem", ">(),")
+                .or_else(|| problem_number(line, "Problem::streaming::<Problem", ">(),"))
+        },
+        id,
+        &format!("    Problem::new::<Problem{id}>(),"),
+    )?;
+    grow_problems(&text)
+}
+
+/// Adds the challenge set `set` to those [`codyssi_2025::registry::set`] finds by name.
+fn register_set(text: &str, set: &str) -> Result<String, String> {
+    insert_sorted(
+        text,
+        |line| {
+            let line = line.trim();
+            if line.starts_with("None => Some(Set::new(") {
+                return Some(String::new());
+            }
+            let (name, _) = line
+                .strip_prefix("Some(\"")?
+                .split_once("\") => Some(Set::new(")?;
+            Some(name.to_owned())
+        },
+        set.to_owned(),
+        &format!(
+            "        Some(\"{set}\") => Some(Set::new(Some(\"{set}\"), &crate::set_{set}::PROBLEMS)),"
+        ),
+    )
+}
+
+/// Adds problem `id` to the `PROBLEMS` of the registry of a challenge set.
+fn register_in_set(text: &str, id: u8) -> Result<String, String> {
+    let text = insert_sorted(
+        text,
+        |line| {
+            let line = line.trim().strip_prefix("Problem::new::<problem")?;
+            line.split_once("::")?.0.parse::<u8>().ok()
+        },
+        id,
+        &format!("    Problem::new::<problem{id}::Problem{id}>(),"),
+    )?;
+    grow_problems(&text)
+}
+
+/// Increments the length of the `PROBLEMS` array.
+fn grow_problems(text: &str) -> Result<String, String> {
+    const DECLARATION: &str = "pub static PROBLEMS: [Problem; ";
+    let start = text
+        .find(DECLARATION)
+        .ok_or("error: found no `PROBLEMS` to register the problem in")?
+        + DECLARATION.len();
+    let end = start
+        + text[start..]
+            .find(']')
+            .ok_or("error: found no length for `PROBLEMS`")?;
+    let length: usize = text[start..end]
+        .parse()
+        .map_err(|_| "error: found no length for `PROBLEMS`")?;
+    Ok(format!("{}{}{}", &text[..start], length + 1, &text[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn problems_are_registered_in_order() {
+        let lib = declare_module(include_str!("../../lib.rs"), "problem200").unwrap();
+        assert!(lib.contains("pub mod problem2;\npub mod problem200;\npub mod problem3;\n"));
+        let registry = register(include_str!("../../registry.rs"), 200).unwrap();
+        assert!(registry.contains("    problem200::Problem200,\n    stream::"));
+        assert!(registry.contains("    Problem::new::<Problem200>(),\n];"));
+        let registered = registry.matches("Problem::new::<").count()
+            + registry.matches("Problem::streaming::<").count();
+        assert!(registry.contains(&format!("pub static PROBLEMS: [Problem; {registered}] = [")));
+        assert_eq!(
+            register(include_str!("../../registry.rs"), 13).unwrap_err(),
+            "error: `problem13::Problem13,` is there already"
+        );
+        // The problems that stream count as registered as well.
+        let without_import =
+            include_str!("../../registry.rs").replace("    problem4::Problem4,\n", "");
+        assert_eq!(
+            register(&without_import, 4).unwrap_err(),
+            "error: `Problem::new::<Problem4>(),` is there already"
+        );
+    }
+
+    #[test]
+    fn sets_are_registered_in_order() {
+        let registry = register_set(include_str!("../../registry.rs"), "test_b").unwrap();
+        let registry = register_set(&registry, "test_a").unwrap();
+        assert!(registry.contains(
+            "        Some(\"test_a\") => Some(Set::new(Some(\"test_a\"), &crate::set_test_a::PROBLEMS)),\n        \
+             Some(\"test_b\") => Some(Set::new(Some(\"test_b\"), &crate::set_test_b::PROBLEMS)),\n        \
+             _ => None,\n"
+        ));
+        assert!(register_set(&registry, "test_b").is_err());
+    }
+
+    #[test]
+    fn existing_files_are_not_overwritten() {
+        let root = std::env::temp_dir().join(format!("codyssi-new-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write(
+            &root.join("src/lib.rs"),
+            "pub mod problem1;\npub mod registry;\n",
+        )
+        .unwrap();
+        write(
+            &root.join("src/registry.rs"),
+            include_str!("../../registry.rs"),
+        )
+        .unwrap();
+
+        let layout = Layout::new(&root, Some("test")).unwrap();
+        assert_eq!(scaffold(&layout, 3).unwrap().len(), 2);
+        assert_eq!(scaffold(&layout, 1).unwrap().len(), 2);
+        assert_eq!(
+            read(&root.join("src/lib.rs")).unwrap(),
+            "pub mod problem1;\npub mod registry;\npub mod set_test;\n"
+        );
+        let set = read(&root.join("src/set_test.rs")).unwrap();
+        assert!(set.contains("pub mod problem1;\npub mod problem3;\n"));
+        assert!(set.contains("[Problem; 2] = [\n    Problem::new::<problem1::Problem1>(),\n"));
+        assert!(
+            read(&root.join("src/bin/set_test_problem3/main.rs"))
+                .unwrap()
+                .starts_with("use codyssi_2025::{run, set_test::problem3::Problem3};")
+        );
+        assert!(
+            read(&root.join("src/set_test/problem3.rs"))
+                .unwrap()
+                .contains("    const PROBLEM: u8 = 3;\n\n    const SET: Option<&'static str> = Some(\"test\");\n\n")
+        );
+        assert!(read(&root.join("src/registry.rs")).unwrap().contains(
+            "        Some(\"test\") => Some(Set::new(Some(\"test\"), &crate::set_test::PROBLEMS)),\n"
+        ));
+
+        // A set that cannot be registered leaves every file as it was.
+        fs::remove_file(root.join("src/registry.rs")).unwrap();
+        let layout_test_c = Layout::new(&root, Some("test_c")).unwrap();
+        assert!(scaffold(&layout_test_c, 1).is_err());
+        assert!(!root.join("src/set_test_c").exists());
+        assert!(!root.join("src/bin/set_test_c_problem1").exists());
+        assert!(
+            !read(&root.join("src/lib.rs"))
+                .unwrap()
+                .contains("set_test_c")
+        );
+
+        let module = root.join("src/set_test/problem3.rs");
+        fs::write(&module, "// solved").unwrap();
+        assert_eq!(
+            scaffold(&layout, 3).unwrap_err(),
+            format!("error: {} already exists", module.display())
+        );
+        assert_eq!(read(&module).unwrap(), "// solved");
+        assert!(Layout::new(&root, Some("Next")).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    CliError,
+    args::Args,
+    generate::write_output,
+    run::{lookup, take_set},
+};
+use codyssi_2025::input;
+use std::path::PathBuf;
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let input = args.option("--input")?.map(PathBuf::from);
+    let output = args.option("--output")?.map(PathBuf::from);
+    let force = args.flag("--force");
+    let set = take_set(&mut args)?;
+    let target = args.positional().ok_or("missing problem number")?;
+    args.finish()?;
+
+    let problem = lookup(set, &target)?;
+    let origin = input::origin(set.name, problem.id, input.as_deref());
+    let input = input::read(set.name, problem.id, input.as_deref())
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let normalized = problem
+        .normalize(&input)
+        .map_err(|err| CliError::Failed(err.diagnostic(&input, &origin)))?;
+    write_output(output, &normalized, force)
+}
@@ -0,0 +1,348 @@
+use crate::table;
+use codyssi_2025::{Answer, Part, bench::format_duration, registry::Solved, trace::Event};
+use std::{str::FromStr, time::Duration};
+
+#[derive(Clone, Copy, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Answer,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "answer" => Ok(Format::Answer),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "invalid format `{s}`, expected text, answer, json or csv"
+            )),
+        }
+    }
+}
+
+/// How trace events are written: as text, or as one JSON object per line.
+#[derive(Clone, Copy)]
+pub enum TraceFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(TraceFormat::Text),
+            "json" => Ok(TraceFormat::Json),
+            _ => Err(format!("invalid trace format `{s}`, expected text or json")),
+        }
+    }
+}
+
+/// Writes `event` in `format`, with no final line break. In text, the fields that span several
+/// lines, such as grids, follow the others on their own indented lines.
+pub fn trace_event(event: &Event, format: TraceFormat) -> String {
+    match format {
+        TraceFormat::Text => {
+            let (blocks, inline): (Vec<_>, Vec<_>) = event
+                .fields
+                .iter()
+                .partition(|(_, value)| value.contains('\n'));
+            let mut text = event.kind.to_owned();
+            for (name, value) in inline {
+                text += &format!(" {name}={value}");
+            }
+            for (name, value) in blocks {
+                text += &format!("\n  {name}:");
+                for line in value.lines() {
+                    text += &format!("\n    {line}");
+                }
+            }
+            text
+        }
+        TraceFormat::Json => {
+            let fields: Vec<String> = [("kind", event.kind.to_owned())]
+                .iter()
+                .chain(event.fields)
+                .map(|(name, value)| format!("{}: {}", json_string(name), json_string(value)))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        }
+    }
+}
+
+/// The outcome of one part: its answer, or why it could not be found.
+pub type PartOutcome = Result<Solved, (Part, String)>;
+
+/// The outcome of each part, or why the input could not be solved at all.
+pub type Outcomes = Result<Vec<PartOutcome>, String>;
+
+/// The outcome of one problem.
+pub type Row = (u8, Outcomes);
+
+/// The outcome of one problem on the input file with this name.
+pub type InputRow = (String, Outcomes);
+
+/// What a row of answers is about: a problem, or one of its inputs in a batch.
+struct Subject<'a> {
+    problem: u8,
+    input: Option<&'a str>,
+}
+
+/// Prints the answers of a single problem.
+pub fn print_one(id: u8, solved: Vec<Solved>, format: Format) {
+    match format {
+        Format::Text => {
+            for Solved {
+                part,
+                answer,
+                cached,
+                ..
+            } in solved
+            {
+                let note = if cached { " (cached)" } else { "" };
+                match answer {
+                    Answer::Unsolvable(reason) => {
+                        println!("Part {part} has no answer: {reason}{note}")
+                    }
+                    answer => println!("The answer to part {part} is {answer}{note}"),
+                }
+            }
+        }
+        _ => print_all(
+            &[(id, Ok(solved.into_iter().map(Ok).collect()))],
+            &[],
+            format,
+        ),
+    }
+}
+
+/// Prints the answers of several problems; `parts` are the columns of the text table.
+pub fn print_all(rows: &[Row], parts: &[Part], format: Format) {
+    let rows: Vec<_> = rows
+        .iter()
+        .map(|(id, outcomes)| {
+            let subject = Subject {
+                problem: *id,
+                input: None,
+            };
+            (subject, outcomes)
+        })
+        .collect();
+    print_rows(&rows, parts, format);
+}
+
+/// Prints the answers of problem `id` on several inputs; `parts` are the columns of the text
+/// table.
+pub fn print_batch(id: u8, rows: &[InputRow], parts: &[Part], format: Format) {
+    let rows: Vec<_> = rows
+        .iter()
+        .map(|(input, outcomes)| {
+            let subject = Subject {
+                problem: id,
+                input: Some(input),
+            };
+            (subject, outcomes)
+        })
+        .collect();
+    print_rows(&rows, parts, format);
+}
+
+fn print_rows(rows: &[(Subject, &Outcomes)], parts: &[Part], format: Format) {
+    let batch = rows.iter().any(|(subject, _)| subject.input.is_some());
+    match format {
+        Format::Text => {
+            let header = [if batch { "input" } else { "problem" }.to_owned()]
+                .into_iter()
+                .chain(parts.iter().map(|part| format!("part {part}")))
+                .chain(["time".to_owned()])
+                .collect();
+            let cells: Vec<Vec<String>> = rows
+                .iter()
+                .map(|(subject, outcomes)| {
+                    let mut row = vec![match subject.input {
+                        Some(input) => input.to_owned(),
+                        None => subject.problem.to_string(),
+                    }];
+                    match outcomes {
+                        Ok(outcomes) => {
+                            row.extend(outcomes.iter().map(|outcome| match outcome {
+                                Ok(solved) => solved.answer.to_string(),
+                                Err((_, message)) => message.clone(),
+                            }));
+                            let solved: Vec<_> = outcomes.iter().flatten().collect();
+                            if !solved.is_empty() && solved.iter().all(|solved| solved.cached) {
+                                row.push("cached".to_owned());
+                            } else {
+                                let elapsed: Duration =
+                                    solved.iter().map(|solved| solved.elapsed).sum();
+                                row.push(format_duration(elapsed));
+                            }
+                        }
+                        Err(message) => row.push(message.clone()),
+                    }
+                    row
+                })
+                .collect();
+            table::print(header, &cells);
+        }
+        Format::Answer => {
+            for outcomes in rows
+                .iter()
+                .filter_map(|(_, outcomes)| outcomes.as_ref().ok())
+            {
+                for Solved { answer, .. } in outcomes.iter().flatten() {
+                    println!("{answer}");
+                }
+            }
+        }
+        Format::Json => {
+            let objects: Vec<String> = rows
+                .iter()
+                .flat_map(|(subject, outcomes)| json_objects(subject, outcomes))
+                .collect();
+            println!("[\n  {}\n]", objects.join(",\n  "));
+        }
+        Format::Csv => {
+            if batch {
+                println!("problem,input,part,type,answer,elapsed_ns,cached,error");
+            } else {
+                println!("problem,part,type,answer,elapsed_ns,cached,error");
+            }
+            for (subject, outcomes) in rows {
+                let key = match subject.input {
+                    Some(input) => format!("{},{}", subject.problem, csv_field(input)),
+                    None => subject.problem.to_string(),
+                };
+                match outcomes {
+                    Ok(outcomes) => {
+                        for outcome in outcomes {
+                            match outcome {
+                                Ok(Solved {
+                                    part,
+                                    answer,
+                                    elapsed,
+                                    cached,
+                                }) => println!(
+                                    "{key},{part},{},{},{},{cached},",
+                                    answer.type_name(),
+                                    csv_field(&answer.to_string()),
+                                    elapsed.as_nanos()
+                                ),
+                                Err((part, message)) => {
+                                    println!("{key},{part},,,,,{}", csv_field(message))
+                                }
+                            }
+                        }
+                    }
+                    Err(message) => println!("{key},,,,,,{}", csv_field(message)),
+                }
+            }
+        }
+    }
+}
+
+fn json_objects(subject: &Subject, outcomes: &Outcomes) -> Vec<String> {
+    let key = match subject.input {
+        Some(input) => format!(
+            r#""problem": {}, "input": {}"#,
+            subject.problem,
+            json_string(input)
+        ),
+        None => format!(r#""problem": {}"#, subject.problem),
+    };
+    match outcomes {
+        Ok(outcomes) => outcomes
+            .iter()
+            .map(|outcome| match outcome {
+                Ok(Solved {
+                    part,
+                    answer,
+                    elapsed,
+                    cached,
+                }) => format!(
+                    r#"{{{key}, "part": {part}, "type": "{}", "answer": {}, "elapsed_ns": {}, "cached": {cached}}}"#,
+                    answer.type_name(),
+                    json_answer(answer),
+                    elapsed.as_nanos()
+                ),
+                Err((part, message)) => format!(
+                    r#"{{{key}, "part": {part}, "error": {}}}"#,
+                    json_string(message)
+                ),
+            })
+            .collect(),
+        Err(message) => vec![format!(r#"{{{key}, "error": {}}}"#, json_string(message))],
+    }
+}
+
+/// Numbers are written as JSON numbers, whatever their size, and the rest as JSON strings.
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Text(text) => json_string(text),
+        Answer::Unsolvable(_) => json_string(&answer.to_string()),
+        number => number.to_string(),
+    }
+}
+
+pub fn json_string(text: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Quotes `text` when it holds a comma, a quote or a line break, as RFC 4180 describes.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaping() {
+        assert_eq!(json_string("4iWAbo%6"), r#""4iWAbo%6""#);
+        assert_eq!(json_string("a\"b\\c\u{1}"), r#""a\"b\\c\u0001""#);
+        assert_eq!(csv_field("S1_0-S1_2"), "S1_0-S1_2");
+        assert_eq!(csv_field("a,\"b\""), r#""a,""b""""#);
+    }
+
+    #[test]
+    fn trace_events() {
+        let fields = [("row", "2".to_owned()), ("grid", "1 2\n3 4\n".to_owned())];
+        let event = Event {
+            kind: "apply",
+            fields: &fields,
+        };
+        assert_eq!(
+            trace_event(&event, TraceFormat::Text),
+            "apply row=2\n  grid:\n    1 2\n    3 4"
+        );
+        assert_eq!(
+            trace_event(&event, TraceFormat::Json),
+            r#"{"kind": "apply", "row": "2", "grid": "1 2\n3 4\n"}"#
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    CliError,
+    args::Args,
+    run::{lookup, take_set},
+    table,
+};
+use codyssi_2025::{
+    params::{self, Overrides},
+    registry::{Problem, ProblemParams, Set},
+};
+use std::path::PathBuf;
+
+/// The `--config` and `--param` options, which must be taken before the positional arguments.
+pub struct ParamArgs {
+    config: Option<PathBuf>,
+    params: Vec<String>,
+}
+
+impl ParamArgs {
+    pub fn take(args: &mut Args) -> Result<Self, String> {
+        Ok(Self {
+            config: args.option("--config")?.map(PathBuf::from),
+            params: args.repeated_option("--param")?,
+        })
+    }
+
+    /// Reads the overrides of the config file, then the `--param` ones, which win.
+    ///
+    /// `--param name=value` applies to `target`, the problem being solved, and
+    /// `--param N.name=value` to problem `N` of `set`, which is how parameters are given with
+    /// `all`.
+    pub fn overrides(self, set: Set, target: Option<u8>) -> Result<Overrides, CliError> {
+        let mut overrides = match self.config {
+            Some(path) => Overrides::load(&path),
+            None => {
+                let path = params::default_path(set.name);
+                if path.exists() {
+                    Overrides::load(&path)
+                } else {
+                    Ok(Overrides::default())
+                }
+            }
+        }
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+
+        for param in self.params {
+            let (name, value) = param
+                .split_once('=')
+                .ok_or_else(|| format!("invalid parameter `{param}`, expected `name=value`"))?;
+            let (problem, name) = match name.split_once('.') {
+                Some((problem, name)) => (lookup(set, problem)?.id, name),
+                None => (
+                    target.ok_or_else(|| {
+                        format!("parameter `{name}` needs a problem, as in `N.{name}={value}`")
+                    })?,
+                    name,
+                ),
+            };
+            overrides.add(problem, name.to_owned(), value.to_owned());
+        }
+        Ok(overrides)
+    }
+}
+
+pub fn resolve(problem: &Problem, overrides: &Overrides) -> Result<ProblemParams, CliError> {
+    problem
+        .params(overrides.get(problem.id))
+        .map_err(|err| CliError::Usage(format!("problem {}: {err}", problem.id)))
+}
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let set = take_set(&mut args)?;
+    let param_args = ParamArgs::take(&mut args)?;
+    let target = args.positional();
+    args.finish()?;
+
+    let selected = match target.as_deref() {
+        None | Some("all") => None,
+        Some(target) => Some(lookup(set, target)?),
+    };
+    let overrides = param_args.overrides(set, selected.map(|problem| problem.id))?;
+    let problems: Vec<&Problem> = match selected {
+        None => set.problems.iter().collect(),
+        Some(problem) => vec![problem],
+    };
+
+    let mut rows = vec![];
+    for problem in problems {
+        for (name, value) in resolve(problem, &overrides)?.describe() {
+            rows.push(vec![problem.id.to_string(), name.to_owned(), value]);
+        }
+    }
+    table::print(
+        ["problem", "parameter", "value"]
+            .map(str::to_owned)
+            .to_vec(),
+        &rows,
+    );
+    Ok(())
+}
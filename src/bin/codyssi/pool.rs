@@ -0,0 +1,91 @@
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
+
+/// A piece of work for the pool, which may outlive the call when it times out.
+pub type Task<T> = Box<dyn FnOnce() -> T + Send>;
+
+/// Runs `tasks` on `jobs` threads, and returns their results in the order of the tasks.
+///
+/// A task that takes longer than `timeout` is reported as failed, and its thread is left to
+/// finish on its own, since threads cannot be stopped; it ends with the process at the latest.
+/// A task that panics is reported as failed too.
+pub fn run<T: Send + 'static>(
+    jobs: usize,
+    timeout: Duration,
+    tasks: Vec<Task<T>>,
+) -> Vec<Result<T, String>> {
+    let count = tasks.len();
+    let tasks = Mutex::new(tasks.into_iter().map(Some).collect::<Vec<_>>());
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..count).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, count.max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= count {
+                        break;
+                    }
+                    let task = tasks.lock().unwrap()[index].take().unwrap();
+                    let result = run_with_timeout(task, timeout);
+                    results.lock().unwrap()[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every task should have been run"))
+        .collect()
+}
+
+fn run_with_timeout<T: Send + 'static>(task: Task<T>, timeout: Duration) -> Result<T, String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // The receiver is gone when the task timed out, and then the result is of no use.
+        let _ = sender.send(task());
+    });
+    receiver.recv_timeout(timeout).map_err(|err| match err {
+        mpsc::RecvTimeoutError::Timeout => format!("timed out after {}s", timeout.as_secs_f64()),
+        mpsc::RecvTimeoutError::Disconnected => "panicked".to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_are_reported_in_order() {
+        let tasks: Vec<Task<u32>> = vec![
+            Box::new(|| 1),
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(5));
+                2
+            }),
+            Box::new(|| panic!("task 3 fails")),
+            Box::new(|| 4),
+        ];
+        let results = run(2, Duration::from_millis(200), tasks);
+        assert_eq!(
+            results,
+            [
+                Ok(1),
+                Err("timed out after 0.2s".to_owned()),
+                Err("panicked".to_owned()),
+                Ok(4)
+            ]
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{
+    CliError,
+    args::Args,
+    params::ParamArgs,
+    run::{lookup, take_set},
+};
+use codyssi_2025::{
+    Part,
+    bench::format_duration,
+    input,
+    params::Override,
+    registry::{Loaded, Problem, ProblemParams, Solved},
+};
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+};
+
+const HELP: &str = "\
+Commands:
+  run [1|2|3]         Solve a part, or every part
+  params              List the parameters and their values
+  set <name=value>    Change a parameter
+  reset               Restore the parameters the session started with
+  views               List the intermediate structures of the solver
+  show <view>         Show an intermediate structure
+  reload              Read and parse the input file again
+  help                List the commands
+  quit                Leave, as does the end of the input
+";
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let input = args.option("--input")?.map(PathBuf::from);
+    let set = take_set(&mut args)?;
+    let param_args = ParamArgs::take(&mut args)?;
+    let target = args.positional().ok_or("missing problem number")?;
+    args.finish()?;
+
+    let problem = lookup(set, &target)?;
+    let path = match input {
+        Some(path) if path == Path::new("-") => {
+            return Err("the REPL reads its commands from stdin, `--input` must be a file".into());
+        }
+        Some(path) => path,
+        None => input::saved_path(set.name, problem.id).ok_or_else(|| {
+            CliError::Failed(format!(
+                "error: no input for problem {}, give one with `--input`",
+                problem.id
+            ))
+        })?,
+    };
+    let overrides = param_args.overrides(set, Some(problem.id))?;
+    let mut session = Session::new(problem, path, overrides.get(problem.id).to_vec())
+        .map_err(CliError::Failed)?;
+    eprintln!(
+        "Loaded {} for problem {}, `help` lists the commands",
+        session.path.display(),
+        problem.id
+    );
+
+    let interactive = io::stdin().is_terminal();
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        if interactive {
+            print!("{}> ", problem.id);
+            let _ = io::stdout().flush();
+        }
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line =
+            line.map_err(|err| CliError::Failed(format!("error: cannot read stdin: {err}")))?;
+        match session.execute(&line) {
+            Ok(Step::Print(text)) => print!("{text}"),
+            Ok(Step::Quit) => break,
+            Err(message) => eprintln!("{message}"),
+        }
+    }
+    Ok(())
+}
+
+enum Step {
+    Print(String),
+    Quit,
+}
+
+/// A problem with its input parsed once, and parameters that change along the session.
+struct Session {
+    problem: &'static Problem,
+    path: PathBuf,
+    loaded: Loaded,
+    initial: Vec<Override>,
+    overrides: Vec<Override>,
+    params: ProblemParams,
+}
+
+impl Session {
+    fn new(
+        problem: &'static Problem,
+        path: PathBuf,
+        overrides: Vec<Override>,
+    ) -> Result<Self, String> {
+        let params = problem
+            .params(&overrides)
+            .map_err(|err| format!("error: problem {}: {err}", problem.id))?;
+        Ok(Self {
+            problem,
+            loaded: load(problem, &path)?,
+            path,
+            initial: overrides.clone(),
+            overrides,
+            params,
+        })
+    }
+
+    fn execute(&mut self, line: &str) -> Result<Step, String> {
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            return Ok(Step::Print(String::new()));
+        };
+        let argument = words.next();
+        if let Some(extra) = words.next() {
+            return Err(format!("error: unexpected argument `{extra}`"));
+        }
+
+        let text = match (command, argument) {
+            ("run", part) => {
+                let parts = match part {
+                    None => Part::ALL.to_vec(),
+                    Some(part) => vec![part.parse().map_err(|err| format!("error: {err}"))?],
+                };
+                parts
+                    .into_iter()
+                    .map(|part| {
+                        let Solved {
+                            answer, elapsed, ..
+                        } = self.loaded.solve(&self.params, part);
+                        format!(
+                            "The answer to part {part} is {answer} ({})\n",
+                            format_duration(elapsed)
+                        )
+                    })
+                    .collect()
+            }
+            ("params", None) => {
+                let params = self.params.describe();
+                if params.is_empty() {
+                    "The problem has no parameters\n".to_owned()
+                } else {
+                    params
+                        .into_iter()
+                        .map(|(name, value)| format!("{name} = {value}\n"))
+                        .collect()
+                }
+            }
+            ("set", Some(assignment)) => {
+                let (name, value) = assignment.split_once('=').ok_or_else(|| {
+                    format!("error: invalid parameter `{assignment}`, expected `name=value`")
+                })?;
+                let mut overrides = self.overrides.clone();
+                overrides.push((name.to_owned(), value.to_owned()));
+                self.params = self
+                    .problem
+                    .params(&overrides)
+                    .map_err(|err| format!("error: {err}"))?;
+                self.overrides = overrides;
+                String::new()
+            }
+            ("reset", None) => {
+                self.params = self
+                    .problem
+                    .params(&self.initial)
+                    .expect("the initial parameters should still be valid");
+                self.overrides = self.initial.clone();
+                String::new()
+            }
+            ("views", None) => {
+                let views = self.loaded.views();
+                if views.is_empty() {
+                    "The problem has no views\n".to_owned()
+                } else {
+                    let width = views.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+                    views
+                        .into_iter()
+                        .map(|(name, about)| format!("{name:width$}  {about}\n"))
+                        .collect()
+                }
+            }
+            ("show", Some(view)) => self
+                .loaded
+                .show(view, &self.params)
+                .ok_or_else(|| format!("error: unknown view `{view}`, `views` lists them"))?,
+            ("reload", None) => {
+                self.loaded = load(self.problem, &self.path)?;
+                format!("Reloaded {}\n", self.path.display())
+            }
+            ("help", None) => HELP.to_owned(),
+            ("quit" | "exit", None) => return Ok(Step::Quit),
+            _ => {
+                return Err(format!(
+                    "error: invalid command `{}`, `help` lists the commands",
+                    line.trim()
+                ));
+            }
+        };
+        Ok(Step::Print(text))
+    }
+}
+
+fn load(problem: &Problem, path: &Path) -> Result<Loaded, String> {
+    let input = input::read_file(path).map_err(|err| format!("error: {err}"))?;
+    // The parsed data borrows from the input for the rest of the session, and an input that is
+    // reloaded is leaked as well, which is of no consequence for the few times it happens.
+    let input: &'static str = Box::leak(input.into_boxed_str());
+    problem
+        .load(input)
+        .map_err(|err| err.diagnostic(input, &path.display().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codyssi_2025::registry;
+    use std::fs;
+
+    fn output(session: &mut Session, line: &str) -> String {
+        match session.execute(line) {
+            Ok(Step::Print(text)) => text,
+            Ok(Step::Quit) => "quit".to_owned(),
+            Err(message) => message,
+        }
+    }
+
+    #[test]
+    fn parameters_change_between_runs() {
+        let path = std::env::temp_dir().join(format!("codyssi-repl-{}.txt", std::process::id()));
+        fs::write(&path, "ABC | 3\nDEF | 1\nGHI | 5\n\nDEF | 1\nGHI | 5\n").unwrap();
+        let problem = registry::problem(15).unwrap();
+        let mut session = Session::new(problem, path.clone(), vec![]).unwrap();
+
+        assert_eq!(output(&mut session, "params"), "id = 500000\n");
+        assert!(output(&mut session, "run 2").starts_with("The answer to part 2 is ABC-GHI ("));
+        assert_eq!(output(&mut session, "set id=2"), "");
+        assert!(output(&mut session, "run 2").starts_with("The answer to part 2 is ABC-DEF ("));
+        assert_eq!(
+            output(&mut session, "set id=x"),
+            "error: invalid value `x` for parameter `id`"
+        );
+        assert_eq!(output(&mut session, "params"), "id = 2\n");
+        assert_eq!(output(&mut session, "reset"), "");
+        assert_eq!(output(&mut session, "params"), "id = 500000\n");
+
+        assert_eq!(
+            output(&mut session, "views"),
+            "tree  the tree of the artifacts, the lower identifiers first under each node\n"
+        );
+        assert_eq!(
+            output(&mut session, "show tree"),
+            "ABC 3\n  < DEF 1\n  > GHI 5\n"
+        );
+        assert_eq!(
+            output(&mut session, "show graph"),
+            "error: unknown view `graph`, `views` lists them"
+        );
+
+        fs::write(&path, "ABC | x\n\nDEF | 1\nGHI | 5\n").unwrap();
+        assert!(output(&mut session, "reload").starts_with("error: expected an identifier"));
+        assert_eq!(output(&mut session, "quit"), "quit");
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,488 @@
+use crate::{
+    CliError,
+    args::Args,
+    output::{self, Format, InputRow, Outcomes, Row, TraceFormat},
+    params::{self, ParamArgs},
+    pool::{self, Task},
+};
+use codyssi_2025::{
+    ParseError, Part,
+    cache::{self, Cache, Key},
+    input,
+    registry::{self, Problem, ProblemParams, Set, Solved},
+    stream::StreamError,
+    trace::{self, Filter},
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let part = args.parsed_option::<Part>("--part")?;
+    let input = args.option("--input")?.map(PathBuf::from);
+    let format = args
+        .parsed_option::<Format>("--format")?
+        .unwrap_or_default();
+    let jobs = args.parsed_option::<usize>("--jobs")?;
+    let timeout = args.parsed_option::<u64>("--timeout")?;
+    let trace_format = args.parsed_option::<TraceFormat>("--trace")?;
+    let trace_kinds = args.option("--trace-kinds")?;
+    let no_cache = args.flag("--no-cache");
+    let stream = args.flag("--stream");
+    let set = take_set(&mut args)?;
+    let param_args = ParamArgs::take(&mut args)?;
+    let target = args.positional().ok_or("missing problem number")?;
+    args.finish()?;
+
+    // A directory of inputs for a single problem runs it on each of them.
+    let batch = target != "all" && input.as_ref().is_some_and(|input| input.is_dir());
+    if target != "all" && !batch && jobs.is_some() {
+        return Err("`--jobs` only applies to `run all` and to a directory of inputs".into());
+    }
+    if jobs.is_none() && timeout.is_some() {
+        return Err("`--timeout` only applies with `--jobs`".into());
+    }
+    let timeout = Duration::from_secs(timeout.unwrap_or(DEFAULT_TIMEOUT_SECS));
+    if (target == "all" || batch) && trace_format.is_some() {
+        return Err("`--trace` only applies to a single problem and input".into());
+    }
+    if trace_format.is_none() && trace_kinds.is_some() {
+        return Err("`--trace-kinds` only applies with `--trace`".into());
+    }
+    if stream && (target == "all" || batch || trace_format.is_some()) {
+        return Err(
+            "`--stream` only applies to a single problem and input, without `--trace`".into(),
+        );
+    }
+    let trace = trace_format.map(|format| {
+        let filter = match trace_kinds {
+            None => Filter::All,
+            Some(kinds) => Filter::Kinds(kinds.split(',').map(str::to_owned).collect()),
+        };
+        (format, filter)
+    });
+
+    // Traced parts are solved again, for their steps.
+    let cache = (!no_cache && trace.is_none()).then(|| Cache::new(&cache::default_dir()));
+    let cache = cache.as_ref();
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    if target == "all" {
+        let overrides = param_args.overrides(set, None)?;
+        let params: Vec<_> = set
+            .problems
+            .iter()
+            .map(|problem| params::resolve(problem, &overrides))
+            .collect::<Result<_, _>>()?;
+        let dirs = [
+            input.unwrap_or_else(|| input::inputs_dir(set.name)),
+            input::fetched_dir(set.name),
+        ];
+        let cases = set
+            .problems
+            .iter()
+            .zip(params)
+            .map(|(problem, params)| Case {
+                problem,
+                input: read_in(&dirs, problem),
+                params: Arc::new(params),
+            })
+            .collect();
+        let outcomes = solve_cases(cases, &parts, jobs, timeout, cache);
+        let rows: Vec<Row> = set
+            .problems
+            .iter()
+            .map(|problem| problem.id)
+            .zip(outcomes)
+            .collect();
+        output::print_all(&rows, &parts, format);
+        Ok(())
+    } else {
+        let problem = lookup(set, &target)?;
+        let overrides = param_args.overrides(set, Some(problem.id))?;
+        let params = params::resolve(problem, &overrides)?;
+        match input {
+            Some(dir) if batch => {
+                let rows = run_batch(problem, &dir, params, &parts, jobs, timeout, cache)?;
+                output::print_batch(problem.id, &rows, &parts, format);
+                Ok(())
+            }
+            input if stream => run_streamed(problem, input, &params, &parts, format),
+            input => run_one(problem, input, &params, &parts, format, trace, cache),
+        }
+    }
+}
+
+/// Takes the `--set` option, the challenge set of the problems, which is this crate's own without
+/// it.
+pub fn take_set(args: &mut Args) -> Result<Set, String> {
+    let name = args.option("--set")?;
+    registry::set(name.as_deref())
+        .ok_or_else(|| format!("unknown challenge set `{}`", name.unwrap_or_default()))
+}
+
+pub fn lookup(set: Set, target: &str) -> Result<&'static Problem, String> {
+    target
+        .parse()
+        .ok()
+        .and_then(|id| set.problem(id))
+        .ok_or_else(|| format!("unknown problem `{target}`"))
+}
+
+fn run_one(
+    problem: &Problem,
+    input: Option<PathBuf>,
+    params: &ProblemParams,
+    parts: &[Part],
+    format: Format,
+    trace: Option<(TraceFormat, Filter)>,
+    cache: Option<&Cache>,
+) -> Result<(), CliError> {
+    let origin = input::origin(problem.set, problem.id, input.as_deref());
+    let input = input::read(problem.set, problem.id, input.as_deref())
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let solve = || match cache {
+        Some(cache) => cache.solve(problem, &input, params, parts),
+        None => problem.solve(&input, params, parts),
+    };
+    // The events go to stderr, to keep stdout for the answers.
+    let solved = match trace {
+        None => solve(),
+        Some((trace_format, filter)) => trace::traced(
+            filter,
+            move |event| eprintln!("{}", output::trace_event(event, trace_format)),
+            solve,
+        ),
+    }
+    .map_err(|err| CliError::Failed(err.diagnostic(&input, &origin)))?;
+    output::print_one(problem.id, solved, format);
+    Ok(())
+}
+
+/// Solves `problem` while reading its input, which is never kept whole, nor cached since the
+/// cache is keyed by the whole input.
+fn run_streamed(
+    problem: &Problem,
+    input: Option<PathBuf>,
+    params: &ProblemParams,
+    parts: &[Part],
+    format: Format,
+) -> Result<(), CliError> {
+    if !problem.can_stream() {
+        let streaming: Vec<_> = registry::set(problem.set)
+            .into_iter()
+            .flat_map(|set| set.problems)
+            .filter(|problem| problem.can_stream())
+            .map(|problem| problem.id.to_string())
+            .collect();
+        return Err(CliError::Failed(format!(
+            "error: problem {} cannot be streamed, only problems {} can",
+            problem.id,
+            streaming.join(", ")
+        )));
+    }
+    let origin = input::origin(problem.set, problem.id, input.as_deref());
+    let mut reader = input::open(problem.set, problem.id, input.as_deref())
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let solved = problem.stream(&mut reader, params, parts).map_err(|err| {
+        CliError::Failed(match err {
+            StreamError::Io(err) => format!("error: cannot read {origin}: {err}"),
+            StreamError::Parse { error, line } => error.diagnostic_of_line(&line, &origin),
+        })
+    })?;
+    output::print_one(problem.id, solved, format);
+    Ok(())
+}
+
+/// Solves `problem` from its input in the first of `dirs` that has one, describing any failure in
+/// a few words.
+pub fn solve_in(
+    dirs: &[PathBuf],
+    problem: &Problem,
+    params: &ProblemParams,
+    parts: &[Part],
+) -> Result<Vec<Solved>, String> {
+    let input = read_in(dirs, problem)?;
+    problem
+        .solve(&input, params, parts)
+        .map_err(|err| parse_failure(&err))
+}
+
+pub fn read_in(dirs: &[PathBuf], problem: &Problem) -> Result<String, String> {
+    let path = input::find_in(dirs, problem.id).ok_or("missing input")?;
+    input::read_file(&path).map_err(|_| "unreadable input".to_owned())
+}
+
+fn parse_failure(err: &ParseError) -> String {
+    format!("parse error at line {}, column {}", err.line, err.column)
+}
+
+/// Solves `problem` from each file of `dir`, in the order of their names.
+fn run_batch(
+    problem: &'static Problem,
+    dir: &Path,
+    params: ProblemParams,
+    parts: &[Part],
+    jobs: Option<usize>,
+    timeout: Duration,
+    cache: Option<&Cache>,
+) -> Result<Vec<InputRow>, CliError> {
+    let cannot_list =
+        |err| CliError::Failed(format!("error: cannot list {}: {err}", dir.display()));
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir).map_err(cannot_list)? {
+        let path = entry.map_err(cannot_list)?.path();
+        if path.is_file() {
+            paths.push(path);
+        }
+    }
+    if paths.is_empty() {
+        return Err(CliError::Failed(format!(
+            "error: no input files in {}",
+            dir.display()
+        )));
+    }
+    paths.sort();
+
+    let params = Arc::new(params);
+    let cases = paths
+        .iter()
+        .map(|path| Case {
+            problem,
+            input: input::read_file(path).map_err(|_| "unreadable input".to_owned()),
+            params: Arc::clone(&params),
+        })
+        .collect();
+    let outcomes = solve_cases(cases, parts, jobs, timeout, cache);
+    let names = paths.iter().map(|path| {
+        path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        )
+    });
+    Ok(names.zip(outcomes).collect())
+}
+
+/// A problem to solve from an input, or why there is no input to solve it from.
+struct Case {
+    problem: &'static Problem,
+    input: Result<String, String>,
+    params: Arc<ProblemParams>,
+}
+
+/// Solves `cases` in turn, or with `jobs`, on that many threads, giving up on the parts that take
+/// longer than `timeout`. The answers in `cache` are taken from it, and the others are added.
+fn solve_cases(
+    cases: Vec<Case>,
+    parts: &[Part],
+    jobs: Option<usize>,
+    timeout: Duration,
+    cache: Option<&Cache>,
+) -> Vec<Outcomes> {
+    match jobs {
+        None => cases
+            .into_iter()
+            .map(
+                |Case {
+                     problem,
+                     input,
+                     params,
+                 }| {
+                    let input = input?;
+                    let solved = match cache {
+                        Some(cache) => cache.solve(problem, &input, &params, parts),
+                        None => problem.solve(&input, &params, parts),
+                    }
+                    .map_err(|err| parse_failure(&err))?;
+                    Ok(solved.into_iter().map(Ok).collect())
+                },
+            )
+            .collect(),
+        Some(jobs) => solve_cases_parallel(cases, parts, jobs, timeout, cache),
+    }
+}
+
+fn solve_cases_parallel(
+    cases: Vec<Case>,
+    parts: &[Part],
+    jobs: usize,
+    timeout: Duration,
+    cache: Option<&Cache>,
+) -> Vec<Outcomes> {
+    let mut outcomes: Vec<Outcomes> = vec![];
+    let mut tasks: Vec<Task<_>> = vec![];
+    // The case, part and cache key of each task.
+    let mut slots = vec![];
+    for Case {
+        problem,
+        input,
+        params,
+    } in cases
+    {
+        // Parsing is quick, so inputs that do not parse are reported once, before solving.
+        let input = input.and_then(|input| match problem.solve(&input, &params, &[]) {
+            Ok(_) => Ok(input),
+            Err(err) => Err(parse_failure(&err)),
+        });
+        match input {
+            Ok(input) => {
+                let key = Key::new(problem, &input, &params);
+                let input: Arc<str> = input.into();
+                let mut case_outcomes = vec![];
+                for &part in parts {
+                    if let Some(answer) = cache.and_then(|cache| cache.get(key, part)) {
+                        case_outcomes.push(Ok(Solved {
+                            part,
+                            answer,
+                            elapsed: Duration::ZERO,
+                            cached: true,
+                        }));
+                        continue;
+                    }
+                    let (input, params) = (Arc::clone(&input), Arc::clone(&params));
+                    slots.push((outcomes.len(), part, key));
+                    tasks.push(Box::new(move || problem.solve(&input, &params, &[part])));
+                }
+                outcomes.push(Ok(case_outcomes));
+            }
+            Err(message) => outcomes.push(Err(message)),
+        }
+    }
+
+    for ((case, part, key), result) in slots.into_iter().zip(pool::run(jobs, timeout, tasks)) {
+        let outcome = match result {
+            Ok(Ok(mut solved)) => {
+                let solved = solved.remove(0);
+                if let Some(cache) = cache {
+                    // An answer that cannot be cached is only solved again next time.
+                    let _ = cache.put(key, part, &solved.answer);
+                }
+                Ok(solved)
+            }
+            Ok(Err(err)) => Err((part, parse_failure(&err))),
+            Err(message) => Err((part, message)),
+        };
+        if let Ok(case_outcomes) = &mut outcomes[case] {
+            case_outcomes.push(outcome);
+        }
+    }
+    // The cached parts come before the solved ones, which the rows expect in order.
+    for case_outcomes in outcomes.iter_mut().flatten() {
+        case_outcomes.sort_by_key(|outcome| match outcome {
+            Ok(solved) => solved.part,
+            Err((part, _)) => *part,
+        });
+    }
+    outcomes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_are_reported_per_input() {
+        let problem = registry::problem(1).unwrap();
+        let params = Arc::new(problem.default_params());
+        let case = |input: Result<&str, &str>| Case {
+            problem,
+            input: input.map(str::to_owned).map_err(str::to_owned),
+            params: Arc::clone(&params),
+        };
+        for jobs in [None, Some(2)] {
+            let cases = vec![
+                case(Ok("8\n1\n+\n")),
+                case(Ok("8\nx\n+\n")),
+                case(Err("unreadable input")),
+            ];
+            let outcomes = solve_cases(cases, &[Part::One], jobs, Duration::from_secs(10), None);
+            let answers: Vec<_> = outcomes
+                .iter()
+                .map(|outcome| match outcome {
+                    Ok(parts) => Ok(parts[0].as_ref().unwrap().answer.to_string()),
+                    Err(message) => Err(message.as_str()),
+                })
+                .collect();
+            assert_eq!(
+                answers,
+                [
+                    Ok("9".to_owned()),
+                    Err("parse error at line 2, column 1"),
+                    Err("unreadable input")
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn fetched_inputs_are_found_after_the_inputs_directory() {
+        let root = std::env::temp_dir().join(format!("codyssi-run-dirs-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let dirs = [root.join("inputs"), root.join("fetched")];
+        let write = |dir: &Path, problem, input| {
+            let path = input::path_in(dir, problem);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, input).unwrap();
+        };
+        write(&dirs[1], 1, "8\n1\n+\n");
+        write(&dirs[0], 2, "not an input");
+        write(&dirs[1], 2, "also not an input");
+
+        // As `verify` solves the problems.
+        let problem = registry::problem(1).unwrap();
+        let solved = solve_in(&dirs, problem, &problem.default_params(), &[Part::One]).unwrap();
+        assert_eq!(solved[0].answer.to_string(), "9");
+        // As `run all` and `bench all` read the inputs.
+        let problem = registry::problem(2).unwrap();
+        assert_eq!(read_in(&dirs, problem).unwrap(), "not an input");
+        let problem = registry::problem(3).unwrap();
+        assert_eq!(read_in(&dirs, problem).unwrap_err(), "missing input");
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn parts_stay_in_order_when_some_are_cached() {
+        let dir = std::env::temp_dir().join(format!("codyssi-run-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let cache = Cache::new(&dir);
+        let problem = registry::problem(1).unwrap();
+        let params = Arc::new(problem.default_params());
+        let input = "8\n1\n+\n";
+        cache.solve(problem, input, &params, &[Part::Two]).unwrap();
+        let case = Case {
+            problem,
+            input: Ok(input.to_owned()),
+            params,
+        };
+        let outcomes = solve_cases(
+            vec![case],
+            &Part::ALL,
+            Some(2),
+            Duration::from_secs(10),
+            Some(&cache),
+        );
+        let parts: Vec<_> = outcomes[0]
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|outcome| {
+                let solved = outcome.as_ref().unwrap();
+                (solved.part, solved.cached)
+            })
+            .collect();
+        assert_eq!(
+            parts,
+            [(Part::One, false), (Part::Two, true), (Part::Three, false)]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
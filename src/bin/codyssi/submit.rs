@@ -0,0 +1,87 @@
+use crate::{
+    CliError,
+    args::Args,
+    params::{self, ParamArgs},
+    run::{lookup, take_set},
+};
+use codyssi_2025::{
+    Answer, Part, input,
+    remote::Site,
+    submissions::{self, Attempt, History, Judgement, Refusal},
+};
+use std::path::PathBuf;
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let input = args.option("--input")?.map(PathBuf::from);
+    let set = take_set(&mut args)?;
+    let param_args = ParamArgs::take(&mut args)?;
+    let target = args.positional().ok_or("missing problem number")?;
+    let part = args.positional().ok_or("missing part")?.parse::<Part>()?;
+    args.finish()?;
+
+    let problem = lookup(set, &target)?;
+    let overrides = param_args.overrides(set, Some(problem.id))?;
+    let params = params::resolve(problem, &overrides)?;
+    let origin = input::origin(set.name, problem.id, input.as_deref());
+    let input = input::read(set.name, problem.id, input.as_deref())
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let solved = problem
+        .solve(&input, &params, &[part])
+        .map_err(|err| CliError::Failed(err.diagnostic(&input, &origin)))?;
+    if let Answer::Unsolvable(reason) = &solved[0].answer {
+        return Err(CliError::Failed(format!(
+            "error: part {part} of problem {} has no answer: {reason}",
+            problem.id
+        )));
+    }
+    let answer = solved[0].answer.to_string();
+
+    let mut history = History::load(&submissions::default_path(set.name))
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let now = submissions::now();
+    history
+        .check(problem.id, part, &answer, now)
+        .map_err(|refusal| {
+            CliError::Failed(match refusal {
+                Refusal::Solved { answer } => format!(
+                    "error: part {part} of problem {} is already solved, with {answer}",
+                    problem.id
+                ),
+                Refusal::Rejected => format!(
+                    "error: {answer} was already rejected for part {part} of problem {}",
+                    problem.id
+                ),
+                Refusal::Cooldown { wait } => format!(
+                    "error: an answer was submitted less than {}s ago, wait {wait}s more",
+                    submissions::COOLDOWN_SECS
+                ),
+            })
+        })?;
+
+    let site = Site::from_env().map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let reply = site
+        .submit_answer(problem.id, part, &answer)
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+    let judgement = Judgement::of_reply(&reply);
+    history
+        .record(Attempt {
+            time: now,
+            problem: problem.id,
+            part,
+            answer: answer.clone(),
+            judgement,
+        })
+        .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+
+    match judgement {
+        Judgement::Correct => {
+            println!("{answer} is correct");
+            Ok(())
+        }
+        Judgement::Incorrect => Err(CliError::Failed(format!("{answer} is incorrect"))),
+        Judgement::Unclear => Err(CliError::Failed(format!(
+            "unexpected reply to {answer}: {}",
+            reply.trim()
+        ))),
+    }
+}
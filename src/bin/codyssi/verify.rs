@@ -0,0 +1,110 @@
+use crate::{
+    CliError,
+    args::Args,
+    run::{lookup, solve_in, take_set},
+    table,
+};
+use codyssi_2025::{
+    Part,
+    answers::{self, Answers, Verdict},
+    input,
+    registry::{Problem, Solved},
+};
+use std::path::PathBuf;
+
+pub fn command(mut args: Args) -> Result<(), CliError> {
+    let answers_path = args.option("--answers")?.map(PathBuf::from);
+    let set = take_set(&mut args)?;
+    let inputs_dir = args
+        .option("--input")?
+        .map_or_else(|| input::inputs_dir(set.name), PathBuf::from);
+    let dirs = [inputs_dir, input::fetched_dir(set.name)];
+    let target = args.positional();
+    args.finish()?;
+
+    let problems: Vec<&Problem> = match target {
+        None => set.problems.iter().collect(),
+        Some(target) => vec![lookup(set, &target)?],
+    };
+
+    let answers = match answers_path {
+        Some(path) => Answers::load(&path),
+        None => {
+            let path = answers::default_path(set.name);
+            if path.exists() {
+                Answers::load(&path)
+            } else {
+                eprintln!(
+                    "note: no answers file at {}, every answer is missing",
+                    path.display()
+                );
+                Ok(Answers::default())
+            }
+        }
+    }
+    .map_err(|err| CliError::Failed(format!("error: {err}")))?;
+
+    let mut rows = vec![];
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for problem in problems {
+        match solve_in(&dirs, problem, &problem.default_params(), &Part::ALL) {
+            Ok(results) => {
+                for Solved { part, answer, .. } in results {
+                    let status = match answers.check(problem.id, part, &answer) {
+                        Verdict::Pass => {
+                            passed += 1;
+                            "pass".to_owned()
+                        }
+                        Verdict::Fail { expected } => {
+                            failed += 1;
+                            format!("FAIL (expected {expected})")
+                        }
+                        Verdict::Missing => {
+                            missing += 1;
+                            "missing".to_owned()
+                        }
+                    };
+                    rows.push(vec![
+                        problem.id.to_string(),
+                        part.to_string(),
+                        answer.to_string(),
+                        status,
+                    ]);
+                }
+            }
+            Err(message) if message == "missing input" => {
+                rows.push(vec![
+                    problem.id.to_string(),
+                    "-".to_owned(),
+                    "-".to_owned(),
+                    "skipped (missing input)".to_owned(),
+                ]);
+            }
+            Err(message) => {
+                failed += 1;
+                rows.push(vec![
+                    problem.id.to_string(),
+                    "-".to_owned(),
+                    "-".to_owned(),
+                    format!("FAIL ({message})"),
+                ]);
+            }
+        }
+    }
+
+    table::print(
+        ["problem", "part", "answer", "status"]
+            .map(str::to_owned)
+            .to_vec(),
+        &rows,
+    );
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(CliError::Failed(format!(
+            "error: {failed} answer(s) do not match the recorded ones"
+        )))
+    }
+}
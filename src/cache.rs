@@ -0,0 +1,223 @@
+//! The answers of the parts already solved, so that running a problem again on the same input,
+//! with the same parameters and the same version of its solver, skips the solving.
+//!
+//! Each answer is a file of the cache directory, `problemN/KEY-partP` (under `set_<name>/` for
+//! another challenge set than this crate's own), holding the type of the answer and the answer,
+//! separated by a tab. The key is a hash of the input, the parameters and the version of the
+//! solver.
+
+use crate::{
+    Answer, ParseError, Part, input,
+    registry::{Problem, ProblemParams, Solved},
+};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+pub fn default_dir() -> PathBuf {
+    input::data_dir().join("cache")
+}
+
+/// What the answers of a problem depend on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Key {
+    set: Option<&'static str>,
+    problem: u8,
+    hash: u64,
+}
+
+impl Key {
+    pub fn new(problem: &Problem, input: &str, params: &ProblemParams) -> Self {
+        let mut hash = Fnv::new();
+        hash.write(problem.version.as_bytes());
+        hash.write(&[0]);
+        for (name, value) in params.describe() {
+            hash.write(format!("{name}={value}").as_bytes());
+            hash.write(&[0]);
+        }
+        hash.write(input.as_bytes());
+        Self {
+            set: problem.set,
+            problem: problem.id,
+            hash: hash.0,
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash, which unlike the hashers of the standard library is specified, so that
+/// the keys stay the same from one build to the next.
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_owned(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, key: Key, part: Part) -> PathBuf {
+        input::set_dir(self.dir.clone(), key.set)
+            .join(format!("problem{}", key.problem))
+            .join(format!("{:016x}-part{part}", key.hash))
+    }
+
+    /// The cached answer to `part`, if there is one. An entry that cannot be read counts as none.
+    pub fn get(&self, key: Key, part: Part) -> Option<Answer> {
+        let entry = fs::read_to_string(self.path(key, part)).ok()?;
+        let (type_name, answer) = entry.split_once('\t')?;
+        Answer::from_typed(type_name, answer)
+    }
+
+    pub fn put(&self, key: Key, part: Part, answer: &Answer) -> io::Result<()> {
+        let path = self.path(key, part);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, format!("{}\t{answer}", answer.type_name()))
+    }
+
+    /// Removes every cached answer, and returns how many there were.
+    pub fn clear(&self) -> io::Result<usize> {
+        let mut count = 0;
+        let problems = match fs::read_dir(&self.dir) {
+            Ok(problems) => problems,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err),
+        };
+        for problem in problems {
+            let problem = problem?.path();
+            if problem.is_dir() {
+                count += fs::read_dir(&problem)?.count();
+                fs::remove_dir_all(&problem)?;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Solves the `parts` of `problem` like [`Problem::solve`], taking the answers that are cached,
+    /// and caching the others. The input is not even parsed when every answer is cached.
+    ///
+    /// An answer that cannot be cached is only solved again next time, so failures to write the
+    /// cache are ignored.
+    pub fn solve(
+        &self,
+        problem: &Problem,
+        input: &str,
+        params: &ProblemParams,
+        parts: &[Part],
+    ) -> Result<Vec<Solved>, ParseError> {
+        let key = Key::new(problem, input, params);
+        let cached: Vec<_> = parts.iter().map(|&part| self.get(key, part)).collect();
+        let missing: Vec<Part> = parts
+            .iter()
+            .zip(&cached)
+            .filter(|(_, answer)| answer.is_none())
+            .map(|(&part, _)| part)
+            .collect();
+        let mut solved = if missing.is_empty() {
+            vec![]
+        } else {
+            problem.solve(input, params, &missing)?
+        }
+        .into_iter();
+        Ok(parts
+            .iter()
+            .zip(cached)
+            .map(|(&part, answer)| match answer {
+                Some(answer) => Solved {
+                    part,
+                    answer,
+                    elapsed: Duration::ZERO,
+                    cached: true,
+                },
+                None => {
+                    let solved = solved.next().expect("every missing part should be solved");
+                    let _ = self.put(key, part, &solved.answer);
+                    solved
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn answers_are_cached_by_input_and_params() {
+        let dir = std::env::temp_dir().join(format!("codyssi-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let cache = Cache::new(&dir);
+        let problem = registry::problem(15).unwrap();
+        let input = "ABC | 3\nDEF | 1\nGHI | 5\n\nDEF | 1\nGHI | 5\n";
+        let params = problem.default_params();
+        let answers = |solved: Vec<Solved>| -> Vec<_> {
+            solved
+                .into_iter()
+                .map(|solved| (solved.answer.to_string(), solved.cached))
+                .collect()
+        };
+
+        let solved = cache.solve(problem, input, &params, &[Part::Two]).unwrap();
+        assert_eq!(answers(solved), [("ABC-GHI".to_owned(), false)]);
+        let solved = cache.solve(problem, input, &params, &Part::ALL).unwrap();
+        assert_eq!(
+            answers(solved),
+            [
+                ("12".to_owned(), false),
+                ("ABC-GHI".to_owned(), true),
+                ("ABC".to_owned(), false)
+            ]
+        );
+
+        let other_params = problem
+            .params(&[("id".to_owned(), "2".to_owned())])
+            .unwrap();
+        let solved = cache
+            .solve(problem, input, &other_params, &[Part::Two])
+            .unwrap();
+        assert_eq!(answers(solved), [("ABC-DEF".to_owned(), false)]);
+        let other_input = input.replace("GHI", "JKL");
+        let solved = cache
+            .solve(problem, &other_input, &params, &[Part::Two])
+            .unwrap();
+        assert_eq!(answers(solved), [("ABC-JKL".to_owned(), false)]);
+
+        // Every answer is cached, so the input is not parsed.
+        let key = Key::new(problem, "not an input", &params);
+        cache.put(key, Part::One, &Answer::U64(42)).unwrap();
+        let solved = cache
+            .solve(problem, "not an input", &params, &[Part::One])
+            .unwrap();
+        assert_eq!(answers(solved), [("42".to_owned(), true)]);
+
+        assert_eq!(cache.clear().unwrap(), 6);
+        assert_eq!(cache.get(key, Part::One), None);
+        assert_eq!(cache.clear().unwrap(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+//! Integer arithmetic for the computations that may outgrow their types on large inputs.
+//!
+//! With the `checked` feature, an overflow panics with the operation and the place in the solver
+//! where it happened, in release builds too. Without it, these are the plain operators, which
+//! only check in debug builds.
+
+use std::{
+    fmt::Display,
+    ops::{Add, Mul, Sub},
+    panic::Location,
+};
+
+pub trait Int:
+    Copy + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn pow(self, exp: u32) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($int:ty),*) => {
+        $(
+            impl Int for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$int>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, other)
+                }
+
+                fn checked_pow(self, exp: u32) -> Option<Self> {
+                    <$int>::checked_pow(self, exp)
+                }
+
+                fn pow(self, exp: u32) -> Self {
+                    <$int>::pow(self, exp)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(u8, u16, u32, u64, u128, usize, i64);
+
+#[track_caller]
+pub fn add<T: Int>(a: T, b: T) -> T {
+    if cfg!(feature = "checked") {
+        match a.checked_add(b) {
+            Some(sum) => sum,
+            None => overflow(a, "+", b),
+        }
+    } else {
+        a + b
+    }
+}
+
+#[track_caller]
+pub fn sub<T: Int>(a: T, b: T) -> T {
+    if cfg!(feature = "checked") {
+        match a.checked_sub(b) {
+            Some(difference) => difference,
+            None => overflow(a, "-", b),
+        }
+    } else {
+        a - b
+    }
+}
+
+#[track_caller]
+pub fn mul<T: Int>(a: T, b: T) -> T {
+    if cfg!(feature = "checked") {
+        match a.checked_mul(b) {
+            Some(product) => product,
+            None => overflow(a, "*", b),
+        }
+    } else {
+        a * b
+    }
+}
+
+#[track_caller]
+pub fn pow<T: Int>(base: T, exp: u32) -> T {
+    if cfg!(feature = "checked") {
+        match base.checked_pow(exp) {
+            Some(power) => power,
+            None => overflow(base, "^", exp),
+        }
+    } else {
+        base.pow(exp)
+    }
+}
+
+/// The sum of `values`, checked as [`add`] is.
+#[track_caller]
+pub fn sum<T: Int>(values: impl IntoIterator<Item = T>) -> T {
+    let mut total = T::ZERO;
+    for value in values {
+        total = add(total, value);
+    }
+    total
+}
+
+/// The product of `values`, checked as [`mul`] is.
+#[track_caller]
+pub fn product<T: Int>(values: impl IntoIterator<Item = T>) -> T {
+    let mut total = T::ONE;
+    for value in values {
+        total = mul(total, value);
+    }
+    total
+}
+
+#[cold]
+#[track_caller]
+fn overflow(a: impl Display, op: &str, b: impl Display) -> ! {
+    let location = Location::caller();
+    panic!("arithmetic overflow computing {a} {op} {b} at {location}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations() {
+        assert_eq!(add(2u8, 3), 5);
+        assert_eq!(sub(2i64, 3), -1);
+        assert_eq!(mul(4u64, 5), 20);
+        assert_eq!(pow(3u128, 4), 81);
+        assert_eq!(sum([1usize, 2, 3]), 6);
+        assert_eq!(product(Vec::<u16>::new()), 1);
+    }
+
+    #[cfg(feature = "checked")]
+    fn panic_message<T: std::fmt::Debug>(result: std::thread::Result<T>) -> String {
+        *result.unwrap_err().downcast::<String>().unwrap()
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn overflow_is_reported_where_it_happens() {
+        let line = line!() + 1;
+        let result = std::panic::catch_unwind(|| sum([200u8, 100]));
+        assert_eq!(
+            panic_message(result),
+            format!("arithmetic overflow computing 200 + 100 at src/checked.rs:{line}:50")
+        );
+        let result = std::panic::catch_unwind(|| pow(2i64, 70));
+        assert!(panic_message(result).starts_with("arithmetic overflow computing 2 ^ 70 at"));
+    }
+}
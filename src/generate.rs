@@ -0,0 +1,109 @@
+//! Random inputs in the textual format of each problem, to stress-test and fuzz the solutions.
+//!
+//! Each problem generates its inputs from an [`Rng`] and a `size`, which is roughly the number of
+//! records of the input. Generated inputs are valid for the default parameters.
+
+use std::ops::RangeInclusive;
+
+/// A small deterministic random number generator (SplitMix64), so that a seed always gives back
+/// the same input.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// An integer of `range`, which must not be empty.
+    pub fn int(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "the range should not be empty");
+        let span = end.abs_diff(start) as u128 + 1;
+        start.wrapping_add(((self.next_u64() as u128 * span) >> 64) as i64)
+    }
+
+    /// An index below `len`, which must be positive.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.int(0..=len as i64 - 1) as usize
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// A string of `len` characters picked from `alphabet`.
+    pub fn word(&mut self, len: usize, alphabet: &[u8]) -> String {
+        (0..len).map(|_| *self.choose(alphabet) as char).collect()
+    }
+}
+
+pub const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+pub const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Part, registry::PROBLEMS};
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.int(-3..=3)));
+        }
+        assert_eq!(rng.int(5..=5), 5);
+        assert!(rng.int(i64::MIN..=i64::MAX) != rng.int(i64::MIN..=i64::MAX));
+    }
+
+    #[test]
+    fn seeds_are_reproducible() {
+        for problem in &PROBLEMS {
+            assert_eq!(problem.generate(42, 12), problem.generate(42, 12));
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_normalized() {
+        for problem in &PROBLEMS {
+            let normalized = problem.normalize(&problem.generate(5, 8)).unwrap();
+            assert_eq!(
+                problem.normalize(&normalized).as_ref(),
+                Ok(&normalized),
+                "problem {}",
+                problem.id
+            );
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for problem in &PROBLEMS {
+            let params = problem.default_params();
+            // The later parts of problem 18 take seconds each, so its own tests solve them.
+            let parts: &[Part] = if problem.id == 18 {
+                &[Part::One]
+            } else {
+                &Part::ALL
+            };
+            for (seed, size) in [(0, 1), (1, 2), (2, 8), (3, 30)] {
+                let input = problem.generate(seed, size);
+                if let Err(err) = problem.solve(&input, &params, parts) {
+                    panic!("{err}\n{input}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+//! A rectangular grid of values stored row by row, with the row and column machinery shared by
+//! the grid problems.
+
+use crate::{ParseError, parse::Source};
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// The row and column of a cell, from the top left corner.
+pub type Position = [usize; 2];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    row_count: usize,
+    col_count: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `row_count` rows of `col_count` cells, given row by row.
+    pub fn from_cells(row_count: usize, col_count: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            row_count * col_count,
+            "a {row_count}x{col_count} grid needs as many cells"
+        );
+        Self {
+            row_count,
+            col_count,
+            cells,
+        }
+    }
+
+    /// A grid with the value `f` gives for each position.
+    pub fn from_fn(row_count: usize, col_count: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..row_count)
+            .flat_map(|row| (0..col_count).map(move |col| [row, col]))
+            .map(&mut f)
+            .collect();
+        Self::from_cells(row_count, col_count, cells)
+    }
+
+    pub fn filled(row_count: usize, col_count: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_cells(row_count, col_count, vec![value; row_count * col_count])
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    pub fn get(&self, [row, col]: Position) -> Option<&T> {
+        (row < self.row_count && col < self.col_count)
+            .then(|| &self.cells[row * self.col_count + col])
+    }
+
+    pub fn get_mut(&mut self, [row, col]: Position) -> Option<&mut T> {
+        (row < self.row_count && col < self.col_count)
+            .then(|| &mut self.cells[row * self.col_count + col])
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.col_count..(row + 1) * self.col_count]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.col_count..(row + 1) * self.col_count]
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.col_count, "column {col} is outside the grid");
+        self.cells[col..].iter().step_by(self.col_count)
+    }
+
+    pub fn col_mut(&mut self, col: usize) -> impl Iterator<Item = &mut T> {
+        assert!(col < self.col_count, "column {col} is outside the grid");
+        self.cells[col..].iter_mut().step_by(self.col_count)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.col_count.max(1))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.col_count).map(|col| self.col(col))
+    }
+
+    /// Moves the cells of `row` by `n` places to the right, those falling off the end wrapping
+    /// around to the start.
+    pub fn shift_row(&mut self, row: usize, n: usize) {
+        let len = self.col_count;
+        self.row_mut(row).rotate_right(n % len);
+    }
+
+    /// Moves the cells of `col` by `n` places down, those falling off the end wrapping around to
+    /// the top.
+    pub fn shift_col(&mut self, col: usize, n: usize) {
+        let n = n % self.row_count;
+        let index = |row: usize| row * self.col_count + col;
+        // Rotating by reversals keeps the cells in place, with no copy of the column.
+        let reverse = |cells: &mut [T], rows: std::ops::Range<usize>| {
+            let (mut top, mut bottom) = (rows.start, rows.end);
+            while top + 1 < bottom {
+                bottom -= 1;
+                cells.swap(index(top), index(bottom));
+                top += 1;
+            }
+        };
+        reverse(&mut self.cells, 0..self.row_count);
+        reverse(&mut self.cells, 0..n);
+        reverse(&mut self.cells, n..self.row_count);
+    }
+
+    /// The grid mirrored along its main diagonal, its rows becoming its columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.col_count, self.row_count, |[row, col]| {
+            self[[col, row]].clone()
+        })
+    }
+
+    /// The grid turned a quarter clockwise, its first column becoming its first row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.col_count, self.row_count, |[row, col]| {
+            self[[self.row_count - 1 - col, row]].clone()
+        })
+    }
+
+    /// The grid turned a quarter counterclockwise, its last column becoming its first row.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.col_count, self.row_count, |[row, col]| {
+            self[[col, self.col_count - 1 - row]].clone()
+        })
+    }
+
+    /// The positions up, down, left and right of `position` that are inside the grid.
+    pub fn neighbours(&self, [row, col]: Position) -> impl Iterator<Item = Position> {
+        [
+            row.checked_sub(1).map(|row| [row, col]),
+            Some([row + 1, col]),
+            col.checked_sub(1).map(|col| [row, col]),
+            Some([row, col + 1]),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|&[row, col]| row < self.row_count && col < self.col_count)
+    }
+}
+
+impl<'a, T: FromStr> Grid<T> {
+    /// Parses lines of values separated by whitespace, as many on each line as on the first.
+    pub fn parse(
+        source: &Source<'a>,
+        text: &'a str,
+        expected_row: &str,
+        expected_value: &str,
+    ) -> Result<Self, ParseError> {
+        let col_count = source
+            .lines(text)
+            .next()
+            .map(|line| line.split_ascii_whitespace().count())
+            .unwrap_or_default();
+        if col_count == 0 {
+            return Err(source.missing(text, expected_row));
+        }
+        let mut row_count = 0;
+        let mut cells = vec![];
+        for line in source.lines(text) {
+            let mut tokens = source.tokens(line);
+            for _ in 0..col_count {
+                cells.push(tokens.parse(expected_value)?);
+            }
+            tokens.end()?;
+            row_count += 1;
+        }
+        Ok(Self::from_cells(row_count, col_count, cells))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {position:?} is outside the grid"))
+    }
+}
+
+/// Writes the rows on their own lines, with the values separated by spaces, as parsed.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for (col, value) in row.iter().enumerate() {
+                if col > 0 {
+                    f.write_str(" ")?;
+                }
+                write!(f, "{value}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(row_count: usize, col_count: usize) -> Grid<usize> {
+        Grid::from_fn(row_count, col_count, |[row, col]| 10 * row + col)
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let mut grid = numbered(2, 3);
+        assert_eq!(grid.row(1), [10, 11, 12]);
+        assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), [2, 12]);
+        for value in grid.col_mut(0) {
+            *value += 100;
+        }
+        grid.row_mut(0)[2] = 7;
+        assert_eq!(grid.to_string(), "100 1 7\n110 11 12\n");
+        assert_eq!(grid.get([2, 0]), None);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.cols().map(|col| col.sum::<usize>()).max(), Some(210));
+    }
+
+    #[test]
+    fn shifts_wrap_around() {
+        let mut grid = numbered(3, 3);
+        grid.shift_row(0, 1);
+        grid.shift_col(1, 4);
+        assert_eq!(grid.to_string(), "2 21 1\n10 0 12\n20 11 22\n");
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = numbered(2, 3);
+        assert_eq!(grid.transpose().to_string(), "0 10\n1 11\n2 12\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "10 0\n11 1\n12 2\n");
+        assert_eq!(
+            grid.rotate_counterclockwise().to_string(),
+            "2 12\n1 11\n0 10\n"
+        );
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = numbered(2, 3);
+        assert_eq!(
+            grid.neighbours([0, 0]).collect::<Vec<_>>(),
+            [[1, 0], [0, 1]]
+        );
+        assert_eq!(grid.neighbours([1, 1]).count(), 3);
+    }
+
+    #[test]
+    fn parse_and_render() {
+        let text = "1 2 3\n4 5 6\n";
+        let source = Source::new(0, text);
+        let grid = Grid::<u8>::parse(&source, text, "a row", "a digit").unwrap();
+        assert_eq!(grid, Grid::from_cells(2, 3, vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(grid.to_string(), text);
+        let text = "1 2\n3\n";
+        let source = Source::new(0, text);
+        let err = Grid::<u8>::parse(&source, text, "a row", "a digit").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
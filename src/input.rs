@@ -0,0 +1,230 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, BufRead, BufReader, IsTerminal, Read},
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+/// Environment variable naming the directory that holds the `problemN/input.txt` files, and the
+/// `set_<name>` directories of the other challenge sets.
+pub const INPUTS_DIR_VAR: &str = "CODYSSI_INPUTS";
+
+/// Environment variable naming the directory of the user data, such as the fetched inputs.
+pub const DATA_DIR_VAR: &str = "CODYSSI_DATA";
+
+#[derive(Debug)]
+pub enum InputError {
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Stdin(io::Error),
+    Missing {
+        set: Option<String>,
+        problem: u8,
+        tried: PathBuf,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Read { path, source } => {
+                write!(f, "cannot read input file {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "cannot read input from stdin: {source}"),
+            InputError::Missing {
+                set,
+                problem,
+                tried,
+            } => {
+                let set_option = match set {
+                    Some(set) => format!(" --set {set}"),
+                    None => String::new(),
+                };
+                write!(
+                    f,
+                    "no input for problem {problem}: {} does not exist \
+                     (pass a path, set {INPUTS_DIR_VAR}, run `codyssi fetch{set_option} {problem}`, \
+                     or pipe the input on stdin)",
+                    tried.display()
+                )
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Read { source, .. } | InputError::Stdin(source) => Some(source),
+            InputError::Missing { .. } => None,
+        }
+    }
+}
+
+/// The inputs directory of challenge set `set`, `None` being this crate's own.
+pub fn inputs_dir(set: Option<&str>) -> PathBuf {
+    let dir = env::var_os(INPUTS_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("src")
+                .join("bin")
+        });
+    set_dir(dir, set)
+}
+
+/// `dir` for this crate's own challenge set, else its `set_<name>` subdirectory.
+pub fn set_dir(dir: PathBuf, set: Option<&str>) -> PathBuf {
+    match set {
+        None => dir,
+        Some(set) => dir.join(format!("set_{set}")),
+    }
+}
+
+/// Where the user data goes: `$CODYSSI_DATA`, or `codyssi` in `$XDG_DATA_HOME` or in
+/// `~/.local/share`.
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(DATA_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    let data_home = env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            PathBuf::from(env::var_os("HOME").unwrap_or_default())
+                .join(".local")
+                .join("share")
+        });
+    data_home.join("codyssi")
+}
+
+/// Where `codyssi fetch` keeps the inputs of `set`, in the same layout as the inputs directory.
+pub fn fetched_dir(set: Option<&str>) -> PathBuf {
+    set_dir(data_dir().join("inputs"), set)
+}
+
+/// Where `codyssi fetch` keeps the input of `problem` of `set`.
+pub fn fetched_path(set: Option<&str>, problem: u8) -> PathBuf {
+    path_in(&fetched_dir(set), problem)
+}
+
+pub fn default_path(set: Option<&str>, problem: u8) -> PathBuf {
+    path_in(&inputs_dir(set), problem)
+}
+
+/// The input file of `problem` of `set` in the inputs directory, else the fetched one, if either
+/// exists.
+pub fn saved_path(set: Option<&str>, problem: u8) -> Option<PathBuf> {
+    find_in(&[inputs_dir(set), fetched_dir(set)], problem)
+}
+
+/// The input file of `problem` in the first of `dirs` that has one.
+pub fn find_in(dirs: &[PathBuf], problem: u8) -> Option<PathBuf> {
+    dirs.iter()
+        .map(|dir| path_in(dir, problem))
+        .find(|path| path.exists())
+}
+
+pub fn path_in(dir: &Path, problem: u8) -> PathBuf {
+    dir.join(format!("problem{problem}")).join("input.txt")
+}
+
+pub fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(InputError::Stdin)?;
+    Ok(input)
+}
+
+/// Reads the input of `problem` of `set` from, in order of preference: the explicit `path`
+/// (`-` meaning stdin), the inputs directory, the fetched inputs, or a piped stdin.
+pub fn read(set: Option<&str>, problem: u8, path: Option<&Path>) -> Result<String, InputError> {
+    match path {
+        Some(path) if path == Path::new("-") => read_stdin(),
+        Some(path) => read_file(path),
+        None => {
+            if let Some(path) = saved_path(set, problem) {
+                read_file(&path)
+            } else if !io::stdin().is_terminal() {
+                read_stdin()
+            } else {
+                Err(InputError::Missing {
+                    set: set.map(str::to_owned),
+                    problem,
+                    tried: default_path(set, problem),
+                })
+            }
+        }
+    }
+}
+
+/// Opens the input of `problem` of `set` from the same places as [`read`], to read it as it goes
+/// rather than all at once.
+pub fn open(
+    set: Option<&str>,
+    problem: u8,
+    path: Option<&Path>,
+) -> Result<Box<dyn BufRead>, InputError> {
+    let open_file = |path: &Path| match fs::File::open(path) {
+        Ok(file) => Ok(Box::new(BufReader::new(file)) as Box<dyn BufRead>),
+        Err(source) => Err(InputError::Read {
+            path: path.to_owned(),
+            source,
+        }),
+    };
+    match path {
+        Some(path) if path == Path::new("-") => Ok(Box::new(io::stdin().lock())),
+        Some(path) => open_file(path),
+        None => {
+            if let Some(path) = saved_path(set, problem) {
+                open_file(&path)
+            } else if !io::stdin().is_terminal() {
+                Ok(Box::new(io::stdin().lock()))
+            } else {
+                Err(InputError::Missing {
+                    set: set.map(str::to_owned),
+                    problem,
+                    tried: default_path(set, problem),
+                })
+            }
+        }
+    }
+}
+
+/// Describes where the input of `problem` of `set` is read from, for diagnostics.
+pub fn origin(set: Option<&str>, problem: u8, path: Option<&Path>) -> String {
+    match path {
+        Some(path) if path == Path::new("-") => "<stdin>".to_owned(),
+        Some(path) => path.display().to_string(),
+        None => match saved_path(set, problem) {
+            Some(path) => path.display().to_string(),
+            None if io::stdin().is_terminal() => default_path(set, problem).display().to_string(),
+            None => "<stdin>".to_owned(),
+        },
+    }
+}
+
+/// The input path given to the per-problem binaries, as first command line argument.
+pub fn path_arg() -> Option<PathBuf> {
+    env::args_os().nth(1).map(PathBuf::from)
+}
+
+/// Entry point helper for the per-problem binaries.
+pub fn read_or_exit(set: Option<&str>, problem: u8) -> String {
+    read(set, problem, path_arg().as_deref()).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        exit(1)
+    })
+}
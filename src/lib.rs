@@ -0,0 +1,114 @@
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod checked;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod params;
+pub mod parse;
+mod part;
+pub mod problem1;
+pub mod problem10;
+pub mod problem11;
+pub mod problem12;
+pub mod problem13;
+pub mod problem14;
+pub mod problem15;
+pub mod problem16;
+pub mod problem17;
+pub mod problem18;
+pub mod problem2;
+pub mod problem3;
+pub mod problem4;
+pub mod problem5;
+pub mod problem6;
+pub mod problem7;
+pub mod problem8;
+pub mod problem9;
+#[cfg(test)]
+mod property;
+pub mod registry;
+pub mod remote;
+pub mod search;
+pub mod stream;
+pub mod submissions;
+pub mod toml;
+pub mod trace;
+
+pub use answer::Answer;
+pub use parse::ParseError;
+pub use part::Part;
+
+use generate::Rng;
+use params::ParamSet;
+use std::process::exit;
+
+/// The common shape of every problem: parse the input once, then solve each part from it.
+pub trait Solution: 'static {
+    const PROBLEM: u8;
+
+    /// The challenge set of the problem, added with `codyssi new --set`, or `None` for this
+    /// crate's own.
+    const SET: Option<&'static str> = None;
+
+    /// The version of the solver, part of the key of its [cached](cache) answers: it must change
+    /// along with anything that may change an answer.
+    const VERSION: &'static str = "1";
+
+    type Data<'a>;
+
+    /// The tunable constants of the problem, or `()` when it has none.
+    type Params: ParamSet + Default;
+
+    /// The intermediate structures of the solver that can be shown, e.g. from the REPL.
+    const VIEWS: &'static [View<Self>] = &[];
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError>;
+
+    /// Writes `data` back in the canonical format of the input, which [`Solution::parse`] reads
+    /// back into the same data.
+    fn print(data: &Self::Data<'_>) -> String;
+
+    /// Writes a random input of about `size` records, in the format [`Solution::parse`] reads.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    fn part1(data: &Self::Data<'_>, params: &Self::Params) -> Answer;
+
+    fn part2(data: &Self::Data<'_>, params: &Self::Params) -> Answer;
+
+    fn part3(data: &Self::Data<'_>, params: &Self::Params) -> Answer;
+
+    fn solve(data: &Self::Data<'_>, params: &Self::Params, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part1(data, params),
+            Part::Two => Self::part2(data, params),
+            Part::Three => Self::part3(data, params),
+        }
+    }
+}
+
+/// A named intermediate structure of a solver, written as text from the parsed input.
+pub struct View<S: Solution + ?Sized> {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub show: for<'a> fn(&S::Data<'a>, &S::Params) -> String,
+}
+
+/// Reads the input of `S` and prints the answers to its three parts, with the default parameters.
+pub fn run<S: Solution>() {
+    let input = input::read_or_exit(S::SET, S::PROBLEM);
+    let data = S::parse(&input).unwrap_or_else(|err| {
+        let origin = input::origin(S::SET, S::PROBLEM, input::path_arg().as_deref());
+        eprintln!("{}", err.diagnostic(&input, &origin));
+        exit(1)
+    });
+    let params = S::Params::default();
+    for part in Part::ALL {
+        println!(
+            "The answer to part {part} is {answer}",
+            answer = S::solve(&data, &params, part)
+        );
+    }
+}
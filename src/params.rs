@@ -0,0 +1,159 @@
+//! The tunable constants of the problems, which default to the values of the contest and can be
+//! overridden by name, from the command line or a configuration file.
+
+use crate::{
+    input,
+    toml::{self, FileError, TomlError},
+};
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The parameters of one problem, set by name from their textual value.
+pub trait ParamSet: Any + Send + Sync {
+    /// Sets the parameter `name` from `value`, or explains why it cannot be set.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    /// The name and current value of each parameter, in the syntax [`ParamSet::set`] accepts.
+    fn describe(&self) -> Vec<(&'static str, String)>;
+
+    /// Checks the constraints between parameters, once every override has been applied.
+    fn check(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// The parameters of the problems that have none.
+impl ParamSet for () {
+    fn set(&mut self, name: &str, _: &str) -> Result<(), String> {
+        Err(unknown(name))
+    }
+
+    fn describe(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+}
+
+pub fn unknown(name: &str) -> String {
+    format!("unknown parameter `{name}`")
+}
+
+pub fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for parameter `{name}`"))
+}
+
+/// Parses `N` values separated by commas, like `10,15,60,3`.
+pub fn parse_array<T: FromStr, const N: usize>(name: &str, value: &str) -> Result<[T; N], String> {
+    value
+        .split(',')
+        .map(|item| parse_value(name, item))
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| format!("parameter `{name}` expects {N} values separated by commas"))
+}
+
+pub fn format_array<T: Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(T::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// A parameter name and the textual value it is set to.
+pub type Override = (String, String);
+
+pub fn default_path(set: Option<&str>) -> PathBuf {
+    input::inputs_dir(set).join("params.toml")
+}
+
+/// Parameter overrides by problem, from a TOML file with one `[problemN]` table per problem.
+#[derive(Debug, Default)]
+pub struct Overrides {
+    by_problem: BTreeMap<u8, Vec<Override>>,
+}
+
+impl Overrides {
+    pub fn parse(text: &str) -> Result<Self, TomlError> {
+        let mut overrides = Self::default();
+        for (name, table) in toml::parse(text)? {
+            let problem = name
+                .strip_prefix("problem")
+                .and_then(|id| id.parse::<u8>().ok())
+                .filter(|id| format!("problem{id}") == name)
+                .ok_or_else(|| TomlError {
+                    line: toml::line_of(text, &name),
+                    message: format!("unexpected table `{name}`, expected `[problemN]`"),
+                })?;
+            for (key, value) in table {
+                overrides.add(problem, key, value.to_string());
+            }
+        }
+        Ok(overrides)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, FileError> {
+        toml::read_file(path, Self::parse)
+    }
+
+    /// Adds an override, which takes precedence over the ones added before it.
+    pub fn add(&mut self, problem: u8, name: String, value: String) {
+        self.by_problem
+            .entry(problem)
+            .or_default()
+            .push((name, value));
+    }
+
+    pub fn get(&self, problem: u8) -> &[Override] {
+        self.by_problem.get(&problem).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Applies `overrides` in order on top of the defaults.
+pub fn with_overrides<P: ParamSet + Default>(overrides: &[Override]) -> Result<P, String> {
+    let mut params = P::default();
+    for (name, value) in overrides {
+        params.set(name, value)?;
+    }
+    params.check()?;
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values() {
+        assert_eq!(parse_value::<u64>("id", " 42"), Ok(42));
+        assert!(parse_value::<u64>("id", "-1").is_err());
+        assert_eq!(parse_array::<u8, 2>("goal", "14,14"), Ok([14, 14]));
+        assert!(parse_array::<u8, 2>("goal", "14").is_err());
+        assert_eq!(format_array(&[10, 15, 60, 3]), "10,15,60,3");
+    }
+
+    #[test]
+    fn overrides_by_problem() {
+        let overrides = Overrides::parse("[problem18]\nlife = 2\nsize = \"5,5,5,3\"\n").unwrap();
+        assert_eq!(
+            overrides.get(18),
+            [
+                ("life".to_owned(), "2".to_owned()),
+                ("size".to_owned(), "5,5,5,3".to_owned())
+            ]
+        );
+        assert!(overrides.get(17).is_empty());
+        assert_eq!(Overrides::parse("\n[seed]\n").unwrap_err().line, 2);
+        assert_eq!(
+            with_overrides::<()>(&overrides.get(18)[..1]),
+            Err(unknown("life"))
+        );
+    }
+}
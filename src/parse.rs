@@ -0,0 +1,465 @@
+use std::{error::Error, fmt::Display, str::FromStr, str::SplitAsciiWhitespace};
+
+/// A syntax error in a puzzle input, located at the offending token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub problem: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending token, empty when something is missing.
+    pub token: String,
+    /// A description of what the grammar allows at this position.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Renders the error as a compiler-style diagnostic, quoting the offending line of `input`.
+    pub fn diagnostic(&self, input: &str, origin: &str) -> String {
+        self.diagnostic_of_line(input.lines().nth(self.line - 1).unwrap_or_default(), origin)
+    }
+
+    /// Renders the error like [`ParseError::diagnostic`], from only the offending line of the
+    /// input, e.g. when the input is [streamed](crate::stream) rather than kept.
+    pub fn diagnostic_of_line(&self, source_line: &str, origin: &str) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = "^".repeat(self.token.chars().count().max(1));
+        format!(
+            "error: {message}\n\
+             {gutter}--> {origin}:{line}:{column}\n\
+             {gutter} |\n\
+             {line} | {source_line}\n\
+             {gutter} | {padding}{underline}",
+            message = self.message(),
+            line = self.line,
+            column = self.column,
+            padding = " ".repeat(self.column - 1),
+        )
+    }
+
+    fn message(&self) -> String {
+        if self.token.is_empty() {
+            format!("expected {}, found nothing", self.expected)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.token)
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "problem {}, line {}, column {}: {}",
+            self.problem,
+            self.line,
+            self.column,
+            self.message()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The characters that separate a keyword from its value, or a value from the next keyword.
+const SEPARATORS: [char; 3] = [':', ',', '|'];
+
+/// The input of a problem, used to locate the tokens borrowed from it.
+///
+/// Every slice the methods return is borrowed from the input, so that an error about it, however
+/// deep in the grammar, points at its exact line and column. Whitespace around lines, sections,
+/// fields and list items is not significant.
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    problem: u8,
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(problem: u8, input: &'a str) -> Self {
+        Self { problem, input }
+    }
+
+    /// Reports `token`, which must be a slice of the input, as not matching `expected`.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .saturating_sub(self.input.as_ptr() as usize)
+            .min(self.input.len());
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            problem: self.problem,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            token: token.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Reports that something matching `expected` is missing after `text`.
+    pub fn missing(&self, text: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&text[text.len()..], expected)
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Splits `text` at the first `delimiter`, and trims the whitespace around both sides.
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: &str,
+        expected: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .map(|(left, right)| (left.trim_ascii(), right.trim_ascii()))
+            .ok_or_else(|| self.error(text, expected))
+    }
+
+    /// The lines of `text` that are not blank, trimmed.
+    pub fn lines(&self, text: &'a str) -> impl Iterator<Item = &'a str> + use<'a> {
+        text.lines()
+            .map(str::trim_ascii)
+            .filter(|line| !line.is_empty())
+    }
+
+    /// Splits the input into `N` sections separated by blank lines, `expected` describing each.
+    /// Several blank lines in a row separate two sections as one does, and the blank lines around
+    /// the input are ignored.
+    pub fn sections<const N: usize>(
+        &self,
+        expected: [&str; N],
+    ) -> Result<[&'a str; N], ParseError> {
+        let mut sections = vec![];
+        let mut current: Option<(usize, usize)> = None;
+        for line in self.input.split('\n') {
+            let start = line.as_ptr() as usize - self.input.as_ptr() as usize;
+            if line.trim_ascii().is_empty() {
+                sections.extend(current.take().map(|(start, end)| &self.input[start..end]));
+            } else {
+                let end = start + line.trim_ascii_end().len();
+                current = Some((current.map_or(start, |(start, _)| start), end));
+            }
+        }
+        sections.extend(current.map(|(start, end)| &self.input[start..end]));
+
+        if let Some(extra) = sections.get(N) {
+            let line = extra.lines().next().unwrap_or_default();
+            return Err(self.error(line, "the end of the input"));
+        }
+        sections.try_into().map_err(|sections: Vec<_>| {
+            self.missing(self.input.trim_ascii_end(), expected[sections.len()])
+        })
+    }
+
+    pub fn tokens(&self, line: &'a str) -> Tokens<'a> {
+        Tokens {
+            source: *self,
+            line,
+            tokens: line.split_ascii_whitespace(),
+        }
+    }
+
+    /// Splits `line` into the values that follow each of `keywords`, in any order, and the head
+    /// of the line before the first keyword. A keyword matches whole words, possibly followed by a separator,
+    /// and may span several.
+    /// The values and the head are trimmed of whitespace and of the separators `:`, `,` and `|`.
+    pub fn record(
+        &self,
+        line: &'a str,
+        keywords: &[&'static str],
+    ) -> Result<Record<'a>, ParseError> {
+        let words: Vec<&'a str> = line.split_ascii_whitespace().collect();
+        let offset = |word: &str| word.as_ptr() as usize - line.as_ptr() as usize;
+
+        // The keywords found, with the offsets of their start and of the end of their last word.
+        let mut found: Vec<(&'static str, usize, usize)> = vec![];
+        let mut i = 0;
+        while i < words.len() {
+            let matching = keywords.iter().find(|keyword| {
+                let keyword_words = keyword.split_ascii_whitespace();
+                keyword_words.clone().count() <= words.len() - i
+                    && keyword_words.zip(&words[i..]).all(|(expected, word)| {
+                        word.strip_prefix(expected)
+                            .is_some_and(|rest| rest.is_empty() || rest.starts_with(SEPARATORS))
+                    })
+            });
+            let Some(&keyword) = matching else {
+                i += 1;
+                continue;
+            };
+            if found.iter().any(|&(other, ..)| other == keyword) {
+                return Err(self.error(words[i], format!("a single `{keyword}`")));
+            }
+            let last = i + keyword.split_ascii_whitespace().count() - 1;
+            let last_len = keyword
+                .split_ascii_whitespace()
+                .last()
+                .unwrap_or_default()
+                .len();
+            found.push((keyword, offset(words[i]), offset(words[last]) + last_len));
+            i = last + 1;
+        }
+
+        let trim = |text: &'a str| {
+            text.trim_matches(|c: char| c.is_ascii_whitespace() || SEPARATORS.contains(&c))
+        };
+        let head_end = found.first().map_or(line.len(), |&(_, start, _)| start);
+        let fields = found
+            .iter()
+            .enumerate()
+            .map(|(index, &(keyword, _, end))| {
+                let next = found
+                    .get(index + 1)
+                    .map_or(line.len(), |&(_, start, _)| start);
+                (keyword, trim(&line[end..next]))
+            })
+            .collect();
+        Ok(Record {
+            source: *self,
+            line,
+            keywords: keywords.to_vec(),
+            head: trim(&line[..head_end]),
+            fields,
+        })
+    }
+
+    /// Parses a list of integers, or of other values, separated by commas or whitespace.
+    pub fn integers<T: FromStr>(
+        &self,
+        text: &'a str,
+        expected: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        let values: Vec<_> = text
+            .split(|c: char| c == ',' || c.is_ascii_whitespace())
+            .filter(|item| !item.is_empty())
+            .map(|item| self.parse(item, expected))
+            .collect::<Result<_, _>>()?;
+        if values.is_empty() {
+            return Err(self.missing(text, expected));
+        }
+        Ok(values)
+    }
+
+    /// Parses a tuple of `N` coordinates, such as `(1, -2, 3)`.
+    pub fn tuple<T: FromStr, const N: usize>(
+        &self,
+        text: &'a str,
+        expected: &str,
+    ) -> Result<[T; N], ParseError> {
+        let text = text.trim_ascii();
+        let inner = text.strip_prefix('(').ok_or_else(|| {
+            self.error(
+                text.get(..1).unwrap_or(text),
+                format!("`(` before {N} coordinates"),
+            )
+        })?;
+        let inner = inner
+            .strip_suffix(')')
+            .ok_or_else(|| self.missing(text, "`)`"))?;
+        let mut coordinates = vec![];
+        for item in inner.split(',').map(str::trim_ascii) {
+            if coordinates.len() == N {
+                return Err(self.error(item, "`)`"));
+            }
+            if item.is_empty() {
+                return Err(self.missing(item, expected));
+            }
+            coordinates.push(self.parse(item, expected)?);
+        }
+        coordinates
+            .try_into()
+            .map_err(|_| self.missing(inner.trim_ascii_end(), format!("`,` and {expected}")))
+    }
+
+    /// Checks that `text` is not empty and only has characters that are `allowed`.
+    pub fn word(
+        &self,
+        text: &'a str,
+        expected: &str,
+        allowed: impl Fn(char) -> bool,
+    ) -> Result<&'a str, ParseError> {
+        self.symbols(text, expected, |c| allowed(c).then_some(()))?;
+        Ok(text)
+    }
+
+    /// Reads each character of `text` as a symbol, which `symbol` maps to a value.
+    pub fn symbols<T>(
+        &self,
+        text: &'a str,
+        expected: &str,
+        mut symbol: impl FnMut(char) -> Option<T>,
+    ) -> Result<Vec<T>, ParseError> {
+        if text.is_empty() {
+            return Err(self.missing(text, expected));
+        }
+        text.char_indices()
+            .map(|(i, c)| symbol(c).ok_or_else(|| self.error(&text[i..i + c.len_utf8()], expected)))
+            .collect()
+    }
+}
+
+/// A line split by its keywords, see [`Source::record`].
+pub struct Record<'a> {
+    source: Source<'a>,
+    line: &'a str,
+    keywords: Vec<&'static str>,
+    head: &'a str,
+    fields: Vec<(&'static str, &'a str)>,
+}
+
+impl<'a> Record<'a> {
+    /// The text before the first keyword, which must not be empty.
+    pub fn head(&self, expected: &str) -> Result<&'a str, ParseError> {
+        if self.head.is_empty() {
+            return Err(self.source.missing(self.head, expected));
+        }
+        Ok(self.head)
+    }
+
+    /// Checks that the line starts with a keyword.
+    pub fn headless(&self) -> Result<(), ParseError> {
+        if self.head.is_empty() {
+            return Ok(());
+        }
+        let keywords: Vec<_> = self
+            .keywords
+            .iter()
+            .map(|keyword| format!("`{keyword}`"))
+            .collect();
+        Err(self
+            .source
+            .error(self.head, format!("one of {}", keywords.join(", "))))
+    }
+
+    /// The value after `keyword`, if the line has it.
+    pub fn get(&self, keyword: &str) -> Option<&'a str> {
+        assert!(
+            self.keywords.contains(&keyword),
+            "`{keyword}` is not a keyword of the record"
+        );
+        self.fields
+            .iter()
+            .find(|&&(other, _)| other == keyword)
+            .map(|&(_, value)| value)
+    }
+
+    /// The value after `keyword`, which must not be empty.
+    pub fn field(&self, keyword: &str, expected: &str) -> Result<&'a str, ParseError> {
+        match self.get(keyword) {
+            None => Err(self.source.missing(self.line, format!("`{keyword}`"))),
+            Some(value) if value.is_empty() => Err(self.source.missing(value, expected)),
+            Some(value) => Ok(value),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, keyword: &str, expected: &str) -> Result<T, ParseError> {
+        let value = self.field(keyword, expected)?;
+        self.source.parse(value, expected)
+    }
+}
+
+/// The whitespace-separated tokens of one line.
+pub struct Tokens<'a> {
+    source: Source<'a>,
+    line: &'a str,
+    tokens: SplitAsciiWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn next(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        self.tokens
+            .next()
+            .ok_or_else(|| self.source.missing(self.line, expected))
+    }
+
+    pub fn parse<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        let token = self.next(expected)?;
+        self.source.parse(token, expected)
+    }
+
+    pub fn keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
+        let expected = format!("`{keyword}`");
+        let token = self.next(&expected)?;
+        if token == keyword {
+            Ok(())
+        } else {
+            Err(self.source.error(token, expected))
+        }
+    }
+
+    pub fn end(mut self) -> Result<(), ParseError> {
+        match self.tokens.next() {
+            None => Ok(()),
+            Some(token) => Err(self.source.error(token, "the end of the line")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_are_separated_by_blank_lines() {
+        let input = "\na\nb\n  \n\nc \n\n";
+        let source = Source::new(0, input);
+        assert_eq!(source.sections(["x", "y"]).unwrap(), ["a\nb", "c"]);
+        let err = source.sections(["x", "y", "z"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (6, 2, "z"));
+        let err = source.sections(["x"]).unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (6, "c"));
+    }
+
+    #[test]
+    fn records_are_split_by_keywords_in_any_order() {
+        let line = "7 AB | Cost:3,  Unique Materials : 12, Quality : 5";
+        let source = Source::new(0, line);
+        let record = source
+            .record(line, &["Quality", "Cost", "Unique Materials"])
+            .unwrap();
+        assert_eq!(record.head("an item").unwrap(), "7 AB");
+        assert_eq!(record.parse::<u8>("Quality", "a quality"), Ok(5));
+        assert_eq!(record.field("Cost", "a cost"), Ok("3"));
+        assert_eq!(record.parse::<u8>("Unique Materials", "a count"), Ok(12));
+        assert!(record.headless().is_err());
+
+        let line = "FROM A TO B";
+        let source = Source::new(0, line);
+        let record = source.record(line, &["FROM", "TO", "AMT"]).unwrap();
+        assert_eq!(record.headless(), Ok(()));
+        assert_eq!(record.get("AMT"), None);
+        let err = record.field("AMT", "an amount").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (12, "`AMT`"));
+        let line = "FROM A TO";
+        let source = Source::new(0, line);
+        let record = source.record(line, &["FROM", "TO"]).unwrap();
+        let err = record.field("TO", "an account").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (10, ""));
+    }
+
+    #[test]
+    fn lists_and_tuples_locate_their_items() {
+        let text = "1, 2 3,4";
+        let source = Source::new(0, text);
+        assert_eq!(source.integers::<u8>(text, "a count"), Ok(vec![1, 2, 3, 4]));
+        let text = "1, x";
+        let source = Source::new(0, text);
+        let err = source.integers::<u8>(text, "a count").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (4, "x"));
+
+        let text = "( 1,-2 , 3)";
+        let source = Source::new(0, text);
+        assert_eq!(source.tuple::<i8, 3>(text, "a coordinate"), Ok([1, -2, 3]));
+        let err = source.tuple::<i8, 2>(text, "a coordinate").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (10, "`)`"));
+        let err = source.tuple::<i8, 4>(text, "a coordinate").unwrap_err();
+        assert_eq!(err.column, 11);
+        let text = "(1, 2";
+        let source = Source::new(0, text);
+        let err = source.tuple::<i8, 2>(text, "a coordinate").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (6, "`)`"));
+    }
+}
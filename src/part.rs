@@ -0,0 +1,39 @@
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+    Three,
+}
+
+impl Part {
+    pub const ALL: [Part; 3] = [Part::One, Part::Two, Part::Three];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+            Part::Three => 3,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.number().fmt(f)
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "3" => Ok(Part::Three),
+            _ => Err(format!("invalid part `{s}`, expected 1, 2 or 3")),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Answer, ParseError, Solution, checked, generate::Rng, parse::Source};
+use std::iter::once;
+
+pub struct Problem1;
+
+impl Solution for Problem1 {
+    const PROBLEM: u8 = 1;
+
+    type Data<'a> = Data;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+type Int = i64;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Op {
+    Add,
+    Sub,
+}
+
+impl Op {
+    #[track_caller]
+    fn apply(self, a: Int, b: Int) -> Int {
+        match self {
+            Op::Add => checked::add(a, b),
+            Op::Sub => checked::sub(a, b),
+        }
+    }
+}
+
+type Data = (Vec<Int>, Vec<Op>);
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem1::PROBLEM, input);
+    let mut lines: Vec<_> = source.lines(input).collect();
+    let operators = lines
+        .pop()
+        .ok_or_else(|| source.missing(input, "a line of `+` and `-`"))?;
+    let ops = source.symbols(operators, "`+` or `-`", |c| match c {
+        '+' => Some(Op::Add),
+        '-' => Some(Op::Sub),
+        _ => None,
+    })?;
+    let ints = lines
+        .into_iter()
+        .map(|line| source.parse(line, "an integer"))
+        .collect::<Result<_, _>>()?;
+    Ok((ints, ops))
+}
+
+fn print_input((ints, ops): &Data) -> String {
+    let mut input: String = ints.iter().map(|int| format!("{int}\n")).collect();
+    input.extend(ops.iter().map(|op| match op {
+        Op::Add => '+',
+        Op::Sub => '-',
+    }));
+    input.push('\n');
+    input
+}
+
+/// Writes `size` pairs of digits and the operators between them.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let count = 2 * size.max(1);
+    let mut input: String = (0..count)
+        .map(|_| format!("{}\n", rng.int(0..=9)))
+        .collect();
+    input.extend((1..count).map(|_| *rng.choose(&['+', '-'])));
+    input.push('\n');
+    input
+}
+
+fn solve_part1(data: &Data) -> i64 {
+    let (ints, ops) = data;
+    ints.iter()
+        .zip(once(&Op::Add).chain(ops.iter()))
+        .fold(0, |acc, (&int, op)| op.apply(acc, int))
+}
+
+fn solve_part2(data: &Data) -> i64 {
+    let (ints, ops) = data;
+    ints.iter()
+        .zip(once(&Op::Add).chain(ops.iter().rev()))
+        .fold(0, |acc, (&int, op)| op.apply(acc, int))
+}
+
+fn solve_part3(data: &Data) -> i64 {
+    let (ints, ops) = data;
+    ints.chunks_exact(2)
+        .map(|pair| checked::add(checked::mul(pair[0], 10), pair[1]))
+        .zip(once(&Op::Add).chain(ops.iter().rev()))
+        .fold(0, |acc, (int, op)| op.apply(acc, int))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+8
+1
+5
+5
+7
+6
+5
+4
+3
+1
+-++-++-++
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 23);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 189);
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    Answer, ParseError, Solution,
+    generate::Rng,
+    grid::{Grid, Position},
+    params::{self, ParamSet},
+    parse::Source,
+    search,
+};
+
+pub struct Problem10;
+
+impl Solution for Problem10 {
+    const PROBLEM: u8 = 10;
+
+    type Data<'a> = Data;
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part2(data, params).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+pub struct Params {
+    /// The position, as `[row, col]`, that the path of part 2 leads to from the top-left corner.
+    pub goal: Position,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { goal: [14, 14] }
+    }
+}
+
+impl ParamSet for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "goal" => self.goal = params::parse_array(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Vec<(&'static str, String)> {
+        vec![("goal", params::format_array(&self.goal))]
+    }
+}
+
+type Danger = i64;
+
+type Data = Grid<Danger>;
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem10::PROBLEM, input);
+    let grid = Grid::parse(&source, input, "a row of danger levels", "a danger level")?;
+    // The search for the safest path needs steps that cost nothing or more.
+    let negative = input
+        .split_ascii_whitespace()
+        .find(|token| token.parse::<Danger>().is_ok_and(|danger| danger < 0));
+    match negative {
+        Some(token) => Err(source.error(token, "a non-negative danger level")),
+        None => Ok(grid),
+    }
+}
+
+fn print_input(data: &Data) -> String {
+    data.to_string()
+}
+
+/// Writes a square grid of danger levels of side `size`, at least 15 to contain the goal.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(15);
+    (0..side)
+        .map(|_| {
+            let row: Vec<_> = (0..side).map(|_| rng.int(1..=9).to_string()).collect();
+            row.join(" ") + "\n"
+        })
+        .collect()
+}
+
+fn solve_part1(data: &Data) -> Danger {
+    let lowest_row_danger: Danger = data.rows().map(|row| row.iter().sum()).min().unwrap();
+    let lowest_col_danger = data.cols().map(|col| col.sum()).min().unwrap();
+    lowest_row_danger.min(lowest_col_danger)
+}
+
+/// The lowest total danger of a path from `start` to `goal`, the danger of both included.
+fn safest_path_danger(grid: &Grid<Danger>, start: Position, goal: Position) -> Danger {
+    // Every further step costs at least the lowest danger of the grid.
+    let lowest_danger = grid.iter().copied().min().unwrap_or_default();
+    let remaining = |&[row, col]: &Position| {
+        (row.abs_diff(goal[0]) + col.abs_diff(goal[1])) as Danger * lowest_danger
+    };
+    let search = search::astar(
+        start,
+        |&position| grid.neighbours(position).map(|next| (next, grid[next])),
+        remaining,
+        |&position| position == goal,
+    );
+    search.goal_cost().map_or(0, |danger| grid[start] + danger)
+}
+
+fn solve_part2(data: &Data, params: &Params) -> Result<Danger, String> {
+    if data.get(params.goal).is_none() {
+        return Err(format!(
+            "the goal `{}` is outside the grid of {} rows and {} columns",
+            params::format_array(&params.goal),
+            data.row_count(),
+            data.col_count()
+        ));
+    }
+    Ok(safest_path_danger(data, [0, 0], params.goal))
+}
+
+fn solve_part3(data: &Data) -> i64 {
+    let goal = [data.row_count() - 1, data.col_count() - 1];
+    safest_path_danger(data, [0, 0], goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+8 9 8 8 9 4 3 9 8 3 2 8 5 3 2
+9 1 7 8 3 1 9 2 1 1 4 4 1 8 6
+8 4 9 4 5 8 1 2 8 5 7 9 2 5 6
+4 9 5 1 2 2 7 2 5 7 2 1 1 4 4
+1 8 7 7 7 2 4 5 6 2 5 6 1 7 2
+3 4 2 1 1 8 8 3 9 4 8 9 4 3 7
+7 2 7 7 4 1 5 5 1 4 3 7 2 1 3
+4 8 5 1 6 5 7 2 2 2 4 4 1 6 6
+8 3 8 3 7 3 3 5 4 4 4 3 9 4 7
+8 2 7 1 2 2 1 9 5 4 7 5 7 8 5
+9 3 2 3 4 8 9 2 5 4 4 1 2 5 7
+8 4 1 1 3 5 6 9 3 2 6 3 8 6 9
+3 1 1 8 6 5 1 1 2 8 2 5 6 3 2
+2 8 9 6 1 3 6 6 2 8 2 7 1 8 1
+7 7 1 2 2 2 2 5 7 6 7 8 8 8 9
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 54);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            solve_part2(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            Ok(106)
+        );
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 106);
+    }
+
+    #[test]
+    fn goal_is_a_parameter() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_part2(&data, &Params { goal: [0, 1] }), Ok(17));
+        assert_eq!(
+            solve_part2(&data, &Params { goal: [3, 15] }),
+            Err("the goal `3,15` is outside the grid of 15 rows and 15 columns".to_owned())
+        );
+    }
+
+    #[test]
+    fn negative_danger() {
+        let Err(err) = parse_input("1 2 3\n4 -5 6\n") else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.token, "-5");
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
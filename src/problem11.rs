@@ -0,0 +1,214 @@
+use crate::{Answer, ParseError, Solution, checked, generate::Rng, parse::Source};
+use std::fmt::Display;
+
+pub struct Problem11;
+
+impl Solution for Problem11 {
+    const PROBLEM: u8 = 11;
+
+    type Data<'a> = Data<'a>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Number<'a> {
+    representation: &'a [u8],
+    base: u8,
+}
+
+impl Display for Number<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            String::from_utf8_lossy(self.representation),
+            self.base
+        )
+    }
+}
+
+impl Number<'_> {
+    fn value(&self) -> u64 {
+        self.representation.iter().copied().fold(0, |acc, x| {
+            checked::add(checked::mul(acc, self.base as u64), value(x) as u64)
+        })
+    }
+}
+
+fn digits(number: u64, base: u8) -> Vec<u8> {
+    if number == 0 {
+        return vec![b'0'];
+    }
+    let base = base as u64;
+    let mut remainder = number;
+    let mut result = vec![];
+    while remainder > 0 {
+        result.push((remainder % base) as u8);
+        remainder /= base;
+    }
+    result.reverse();
+    result
+}
+
+const ADDITIONAL_CHARS: &[u8] = b"!@#$%^";
+
+fn value(c: u8) -> u8 {
+    try_value(c).unwrap()
+}
+
+fn try_value(c: u8) -> Option<u8> {
+    if c.is_ascii_digit() {
+        Some(c - b'0')
+    } else if c.is_ascii_uppercase() {
+        Some(c - b'A' + 10)
+    } else if c.is_ascii_lowercase() {
+        Some(c - b'a' + 36)
+    } else {
+        ADDITIONAL_CHARS
+            .iter()
+            .copied()
+            .position(|a| a == c)
+            .map(|i| 62 + i as u8)
+    }
+}
+
+fn as_char(digit: u8) -> u8 {
+    if digit <= 9 {
+        b'0' + digit
+    } else if digit <= 35 {
+        b'A' + digit - 10
+    } else if digit <= 61 {
+        b'a' + digit - 36
+    } else if digit <= 67 {
+        ADDITIONAL_CHARS[digit as usize - 62]
+    } else {
+        panic!();
+    }
+}
+
+type Data<'a> = Vec<Number<'a>>;
+
+fn parse_input(input: &str) -> Result<Data<'_>, ParseError> {
+    let source = Source::new(Problem11::PROBLEM, input);
+    source
+        .lines(input)
+        .map(|line| {
+            let mut tokens = source.tokens(line);
+            let representation = tokens.next("a number")?;
+            let base_token = tokens.next("a base between 2 and 68")?;
+            tokens.end()?;
+            let base = match source.parse(base_token, "a base between 2 and 68")? {
+                base @ 2..=68 => base,
+                _ => return Err(source.error(base_token, "a base between 2 and 68")),
+            };
+            let representation =
+                source.word(representation, &format!("a digit in base {base}"), |c| {
+                    c.is_ascii() && try_value(c as u8).is_some_and(|value| value < base)
+                })?;
+            Ok(Number {
+                representation: representation.as_bytes(),
+                base,
+            })
+        })
+        .collect()
+}
+
+fn print_input(data: &Data) -> String {
+    data.iter().map(|number| format!("{number}\n")).collect()
+}
+
+/// Writes `size` numbers in random bases.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let base = rng.int(2..=68) as u8;
+            let digits: Vec<_> = digits(rng.int(1..=1 << 40) as u64, base)
+                .into_iter()
+                .map(as_char)
+                .collect();
+            format!("{} {base}\n", str::from_utf8(&digits).unwrap())
+        })
+        .collect()
+}
+
+fn solve_part1(data: &Data) -> u64 {
+    data.iter().map(|number| number.value()).max().unwrap()
+}
+
+fn solve_part2(data: &Data) -> String {
+    let digits = digits(checked::sum(data.iter().map(|number| number.value())), 68);
+    let chars: Vec<_> = digits.into_iter().map(as_char).collect();
+    str::from_utf8(&chars).unwrap().to_owned()
+}
+
+fn solve_part3(data: &Data) -> u64 {
+    let sum = checked::sum(data.iter().map(|number| number.value()));
+    for base in 2.. {
+        let square = base * base;
+        let max_value = square * square - 1;
+        if max_value >= sum {
+            return base;
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32IED4E6L4 22
+1111300022221031003013 4
+1C1117A3BA88 13
+1100010000010010010001111000000010001100101 2
+7AJ5G2AB4F 22
+k6IHxTD 61
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 9047685997827);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), "4iWAbo%6");
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 2366);
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    Answer, ParseError, Solution,
+    generate::Rng,
+    grid::Grid,
+    parse::{Source, Tokens},
+    trace,
+};
+use std::{collections::VecDeque, fmt::Display};
+
+pub struct Problem12;
+
+impl Solution for Problem12 {
+    const PROBLEM: u8 = 12;
+
+    type Data<'a> = Data;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+type Amplitude = u64;
+
+const AMPLITUDE_MODULO: Amplitude = 1073741823 + 1;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Domain {
+    All,
+    Row(usize),
+    Col(usize),
+}
+
+impl Display for Domain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Domain::All => write!(f, "ALL"),
+            Domain::Row(i) => write!(f, "ROW {}", i + 1),
+            Domain::Col(j) => write!(f, "COL {}", j + 1),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Operator {
+    Shift(usize),
+    Add(Amplitude),
+    Sub(Amplitude),
+    Mul(Amplitude),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Instruction {
+    operator: Operator,
+    domain: Domain,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let domain = self.domain;
+        match self.operator {
+            Operator::Shift(n) => write!(f, "SHIFT {domain} BY {n}"),
+            Operator::Add(y) => write!(f, "ADD {y} {domain}"),
+            Operator::Sub(y) => write!(f, "SUB {y} {domain}"),
+            Operator::Mul(y) => write!(f, "MULTIPLY {y} {domain}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Control {
+    Act,
+    Cycle,
+}
+
+/// The line that follows each `TAKE`.
+impl Display for Control {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Control::Act => write!(f, "ACT"),
+            Control::Cycle => write!(f, "CYCLE"),
+        }
+    }
+}
+
+impl Grid<Amplitude> {
+    fn apply(&mut self, instruction: &Instruction) {
+        match instruction.operator {
+            Operator::Shift(n) => match instruction.domain {
+                Domain::All => unreachable!("the parser only accepts a SHIFT of a row or a column"),
+                Domain::Row(i) => {
+                    self.shift_row(i, n);
+                }
+                Domain::Col(j) => {
+                    self.shift_col(j, n);
+                }
+            },
+            Operator::Add(y) => {
+                let func = |x| (x + y) % AMPLITUDE_MODULO;
+                match instruction.domain {
+                    Domain::All => self.apply_all(func),
+                    Domain::Row(i) => self.apply_row(i, func),
+                    Domain::Col(j) => self.apply_col(j, func),
+                }
+            }
+            Operator::Sub(y) => {
+                let func = |x| (x + AMPLITUDE_MODULO - y) % AMPLITUDE_MODULO;
+                match instruction.domain {
+                    Domain::All => self.apply_all(func),
+                    Domain::Row(i) => self.apply_row(i, func),
+                    Domain::Col(j) => self.apply_col(j, func),
+                }
+            }
+            Operator::Mul(y) => {
+                let func = |x| (x * y) % AMPLITUDE_MODULO;
+                match instruction.domain {
+                    Domain::All => self.apply_all(func),
+                    Domain::Row(i) => self.apply_row(i, func),
+                    Domain::Col(j) => self.apply_col(j, func),
+                }
+            }
+        }
+        trace::emit("apply", || {
+            vec![
+                ("instruction", format!("{instruction:?}")),
+                ("grid", self.to_string()),
+            ]
+        });
+    }
+
+    fn apply_all<F>(&mut self, func: F)
+    where
+        F: Fn(Amplitude) -> Amplitude,
+    {
+        for value in self.iter_mut() {
+            *value = func(*value);
+        }
+    }
+
+    fn apply_row<F>(&mut self, i: usize, func: F)
+    where
+        F: Fn(Amplitude) -> Amplitude,
+    {
+        for value in self.row_mut(i) {
+            *value = func(*value);
+        }
+    }
+
+    fn apply_col<F>(&mut self, j: usize, func: F)
+    where
+        F: Fn(Amplitude) -> Amplitude,
+    {
+        for value in self.col_mut(j) {
+            *value = func(*value);
+        }
+    }
+}
+
+fn highest_amplitude_sum(grid: &Grid<Amplitude>) -> Amplitude {
+    let highest_row_amplitude: Amplitude = grid.rows().map(|row| row.iter().sum()).max().unwrap();
+    let highest_col_amplitude = grid.cols().map(|col| col.sum()).max().unwrap();
+    highest_row_amplitude.max(highest_col_amplitude)
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Data {
+    grid: Grid<Amplitude>,
+    instructions: Vec<Instruction>,
+    control: Vec<Control>,
+}
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem12::PROBLEM, input);
+    let [grid, instructions, control] = source.sections([
+        "a grid of amplitudes",
+        "a section of instructions",
+        "a section of flow control",
+    ])?;
+
+    let grid = Grid::parse(&source, grid, "a row of amplitudes", "an amplitude")?;
+
+    let instructions = source
+        .lines(instructions)
+        .map(|line| parse_instruction(&source, line, &grid))
+        .collect::<Result<_, _>>()?;
+
+    let mut lines = source.lines(control);
+    let mut control = vec![];
+    while let Some(line) = lines.next() {
+        if line != "TAKE" {
+            return Err(source.error(line, "TAKE"));
+        }
+        let line = lines
+            .next()
+            .ok_or_else(|| source.missing(line, "ACT or CYCLE on the next line"))?;
+        control.push(match line {
+            "ACT" => Control::Act,
+            "CYCLE" => Control::Cycle,
+            _ => return Err(source.error(line, "ACT or CYCLE")),
+        });
+    }
+
+    Ok(Data {
+        grid,
+        instructions,
+        control,
+    })
+}
+
+fn print_input(data: &Data) -> String {
+    let mut input = data.grid.to_string();
+    input.push('\n');
+    input.extend(
+        data.instructions
+            .iter()
+            .map(|instruction| format!("{instruction}\n")),
+    );
+    input.push('\n');
+    input.extend(
+        data.control
+            .iter()
+            .map(|control| format!("TAKE\n{control}\n")),
+    );
+    input
+}
+
+/// Writes a square grid of side `size`, clamped to between 2 and 30, `size` instructions and
+/// about twice as many actions, too few acting ones to run out of instructions.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let side = size.clamp(2, 30);
+    let mut input = String::new();
+    for _ in 0..side {
+        let row: Vec<_> = (0..side).map(|_| rng.int(1..=100).to_string()).collect();
+        input += &(row.join(" ") + "\n");
+    }
+    input.push('\n');
+    let count = size.max(1);
+    for _ in 0..count {
+        let index = rng.index(side) + 1;
+        let domain = *rng.choose(&["ROW", "COL"]);
+        input += &if rng.index(4) == 0 {
+            format!("SHIFT {domain} {index} BY {}\n", rng.index(side) + 1)
+        } else {
+            let operator = *rng.choose(&["ADD", "SUB", "MULTIPLY"]);
+            let domain = match rng.index(3) {
+                0 => "ALL".to_owned(),
+                _ => format!("{domain} {index}"),
+            };
+            format!("{operator} {} {domain}\n", rng.int(1..=100))
+        };
+    }
+    input.push('\n');
+    let mut acts = 0;
+    for i in 0..2 * count - 1 {
+        let act = i == 0 || (acts + 1 < count && rng.index(2) == 0);
+        acts += act as usize;
+        input += if act { "TAKE\nACT\n" } else { "TAKE\nCYCLE\n" };
+    }
+    input
+}
+
+fn parse_instruction(
+    source: &Source,
+    line: &str,
+    grid: &Grid<Amplitude>,
+) -> Result<Instruction, ParseError> {
+    let mut tokens = source.tokens(line);
+
+    let operator_token = tokens.next("an operator")?;
+    let (operator, domain) = match operator_token {
+        "SHIFT" => {
+            let domain_token = tokens.next("ROW or COL")?;
+            let domain = parse_domain(source, domain_token, &mut tokens, grid)?;
+            if let Domain::All = domain {
+                return Err(source.error(domain_token, "ROW or COL"));
+            }
+            tokens.keyword("BY")?;
+            (Operator::Shift(tokens.parse("a shift amount")?), domain)
+        }
+        "ADD" | "SUB" | "MULTIPLY" => {
+            let value = tokens.parse("an amplitude")?;
+            let operator = match operator_token {
+                "ADD" => Operator::Add(value),
+                "SUB" => Operator::Sub(value),
+                _ => Operator::Mul(value),
+            };
+            let domain_token = tokens.next("ALL, ROW or COL")?;
+            (
+                operator,
+                parse_domain(source, domain_token, &mut tokens, grid)?,
+            )
+        }
+        _ => return Err(source.error(operator_token, "SHIFT, ADD, SUB or MULTIPLY")),
+    };
+    tokens.end()?;
+
+    Ok(Instruction { operator, domain })
+}
+
+fn parse_domain(
+    source: &Source,
+    domain_token: &str,
+    tokens: &mut Tokens,
+    grid: &Grid<Amplitude>,
+) -> Result<Domain, ParseError> {
+    let mut index = |count: usize| -> Result<usize, ParseError> {
+        let expected = format!("an index between 1 and {count}");
+        let token = tokens.next(&expected)?;
+        match source.parse(token, &expected)? {
+            index @ 1.. if index <= count => Ok(index - 1),
+            _ => Err(source.error(token, expected)),
+        }
+    };
+    match domain_token {
+        "ALL" => Ok(Domain::All),
+        "ROW" => Ok(Domain::Row(index(grid.row_count())?)),
+        "COL" => Ok(Domain::Col(index(grid.col_count())?)),
+        _ => Err(source.error(domain_token, "ALL, ROW or COL")),
+    }
+}
+
+fn solve_part1(data: &Data) -> Amplitude {
+    let mut grid = data.grid.clone();
+
+    for instruction in data.instructions.iter() {
+        grid.apply(instruction);
+    }
+
+    highest_amplitude_sum(&grid)
+}
+
+fn solve_part2(data: &Data) -> Amplitude {
+    let mut grid = data.grid.clone();
+
+    let mut instructions: VecDeque<_> = data.instructions.iter().copied().collect();
+
+    for &action in data.control.iter() {
+        let instruction = instructions.pop_front().unwrap();
+        match action {
+            Control::Act => grid.apply(&instruction),
+            Control::Cycle => instructions.push_back(instruction),
+        }
+    }
+
+    highest_amplitude_sum(&grid)
+}
+
+fn solve_part3(data: &Data) -> Amplitude {
+    let mut grid = data.grid.clone();
+
+    let mut instructions: VecDeque<_> = data.instructions.iter().copied().collect();
+
+    let mut actions = data.control.iter().copied().cycle();
+
+    while let Some(instruction) = instructions.pop_front() {
+        match actions.next().unwrap() {
+            Control::Act => grid.apply(&instruction),
+            Control::Cycle => instructions.push_back(instruction),
+        }
+    }
+
+    highest_amplitude_sum(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+44 71 59 57 91 3
+50 43 67 80 38 66
+9 15 30 14 11 34
+35 6 100 24 35 97
+17 55 87 34 52 20
+69 66 74 64 90 42
+
+SHIFT COL 2 BY 1
+MULTIPLY 4 COL 5
+SUB 28 ALL
+SHIFT COL 4 BY 2
+MULTIPLY 4 ROW 4
+ADD 26 ROW 3
+SHIFT COL 4 BY 2
+ADD 68 ROW 2
+
+TAKE
+CYCLE
+TAKE
+ACT
+TAKE
+CYCLE
+TAKE
+ACT
+TAKE
+ACT
+TAKE
+CYCLE
+TAKE
+ACT
+TAKE
+CYCLE
+TAKE
+ACT
+TAKE
+ACT
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 3221225725);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 3221225717);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 3221225717);
+    }
+
+    #[test]
+    fn shift_needs_row_or_col() {
+        let Err(err) = parse_input("1 2\n3 4\n\nSHIFT ALL BY 1\n\nTAKE\nACT\n") else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!((err.line, err.column), (4, 7));
+        assert_eq!(err.token, "ALL");
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
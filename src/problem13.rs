@@ -0,0 +1,251 @@
+use crate::{
+    Answer, ParseError, Solution, View,
+    generate::{Rng, UPPERCASE},
+    parse::Source,
+    search,
+};
+use std::{collections::BTreeSet, fmt::Display};
+
+pub struct Problem13;
+
+impl Solution for Problem13 {
+    const PROBLEM: u8 = 13;
+
+    type Data<'a> = Data<'a>;
+
+    type Params = ();
+
+    const VIEWS: &'static [View<Self>] = &[View {
+        name: "adjacency",
+        about: "the paths leaving each location, with their lengths",
+        show: show_adjacency,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+type Location<'a> = &'a str;
+
+type Length = i64;
+
+#[derive(PartialEq, Debug)]
+pub struct Edge<'a> {
+    begin: Location<'a>,
+    end: Location<'a>,
+    length: Length,
+}
+
+impl Display for Edge<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {} | {}", self.begin, self.end, self.length)
+    }
+}
+
+type Data<'a> = Vec<Edge<'a>>;
+
+const START: &str = "STT";
+
+fn parse_input(input: &str) -> Result<Data<'_>, ParseError> {
+    let source = Source::new(Problem13::PROBLEM, input);
+    source
+        .lines(input)
+        .map(|line| {
+            let (edge, length) = source.split_once(line, "|", "a path `A -> B | LENGTH`")?;
+            let (begin, end) = source.split_once(edge, "->", "a path `A -> B`")?;
+            // The search for the shortest paths needs lengths of zero or more.
+            let length = match source.parse(length, "a length")? {
+                ..0 => return Err(source.error(length, "a non-negative length")),
+                length => length,
+            };
+            Ok(Edge { begin, end, length })
+        })
+        .collect()
+}
+
+fn print_input(data: &Data) -> String {
+    data.iter().map(|edge| format!("{edge}\n")).collect()
+}
+
+/// Writes paths from `STT` to `size` other locations, all reachable, and about `size` / 4 more
+/// paths between random locations.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut locations = vec![START.to_owned()];
+    while locations.len() <= size {
+        let location = rng.word(3, UPPERCASE);
+        if !locations.contains(&location) {
+            locations.push(location);
+        }
+    }
+    let mut paths: Vec<_> = (1..locations.len())
+        .map(|end| (rng.index(end), end))
+        .collect();
+    for _ in 0..size / 4 {
+        let begin = rng.index(locations.len());
+        paths.push((begin, (begin + 1 + rng.index(size)) % locations.len()));
+    }
+    paths
+        .into_iter()
+        .map(|(begin, end)| {
+            let length = rng.int(1..=20);
+            format!("{} -> {} | {length}\n", locations[begin], locations[end])
+        })
+        .collect()
+}
+
+/// The edges leaving `location`, with their lengths.
+fn edges_from<'a>(
+    data: &Data<'a>,
+    location: Location<'a>,
+) -> impl Iterator<Item = (Location<'a>, Length)> {
+    data.iter()
+        .filter(move |edge| edge.begin == location)
+        .map(|edge| (edge.end, edge.length))
+}
+
+fn show_adjacency(data: &Data, _: &()) -> String {
+    let locations: BTreeSet<_> = data.iter().map(|edge| edge.begin).collect();
+    locations
+        .into_iter()
+        .map(|location| {
+            let ends: Vec<_> = edges_from(data, location)
+                .map(|(end, length)| format!("{end} ({length})"))
+                .collect();
+            format!("{location} -> {}\n", ends.join(", "))
+        })
+        .collect()
+}
+
+/// The product of the three largest of `lengths`.
+fn largest_product(lengths: impl Iterator<Item = Length>) -> Length {
+    let mut lengths: Vec<_> = lengths.collect();
+    lengths.sort_unstable();
+    lengths.into_iter().rev().take(3).product()
+}
+
+fn solve_part1(data: &Data) -> Length {
+    let search = search::bfs(
+        START,
+        |&location| edges_from(data, location).map(|(end, _)| end),
+        |_| false,
+    );
+    largest_product(search.costs.into_values().map(|steps| steps as Length))
+}
+
+#[derive(Clone)]
+struct Candidate<'a> {
+    location: Location<'a>,
+    distance: Length,
+}
+
+fn solve_part2(data: &Data) -> Length {
+    let search = search::dijkstra(START, |&location| edges_from(data, location), |_| false);
+    largest_product(search.costs.into_values())
+}
+
+fn longest_cycle(graph: &[Edge], current_path: &[Candidate]) -> Length {
+    match current_path.last() {
+        None => 0,
+        Some(end) => match current_path
+            .iter()
+            .rev()
+            .skip(1)
+            .find(|x| x.location == end.location)
+        {
+            Some(begin) => end.distance - begin.distance,
+            None => graph
+                .iter()
+                .filter(|edge| edge.begin == end.location)
+                .map(|edge| {
+                    let mut candidate_path = current_path.to_vec();
+                    candidate_path.push(Candidate {
+                        location: edge.end,
+                        distance: end.distance + edge.length,
+                    });
+                    longest_cycle(graph, &candidate_path)
+                })
+                .max()
+                .unwrap_or_default(),
+        },
+    }
+}
+
+fn solve_part3(data: &Data) -> Length {
+    longest_cycle(
+        data,
+        &[Candidate {
+            location: START,
+            distance: 0,
+        }],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+STT -> MFP | 5
+AIB -> ZGK | 6
+ZGK -> KVX | 20
+STT -> GXS | 10
+MFP -> ZGK | 8
+GXS -> AIB | 9
+AIB -> STT | 17
+MFP -> GXS | 3
+KVX -> AIB | 7
+ZGK -> STT | 11
+GXS -> MFP | 4
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 12);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 7293);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 66);
+    }
+
+    #[test]
+    fn negative_length() {
+        let Err(err) = parse_input("STT -> MFP | 5\nMFP -> ZGK | -8\n") else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!((err.line, err.column), (2, 14));
+        assert_eq!(err.token, "-8");
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{
+    Answer, ParseError, Solution,
+    generate::{Rng, UPPERCASE},
+    params::{self, ParamSet},
+    parse::Source,
+};
+use std::{cmp::Ordering, collections::HashMap};
+
+pub struct Problem14;
+
+impl Solution for Problem14 {
+    const PROBLEM: u8 = 14;
+
+    type Data<'a> = Data<'a>;
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part2(data, params).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part3(data, params).into()
+    }
+}
+
+pub struct Params {
+    /// The cost budget of part 2.
+    pub part2_budget: i64,
+    /// The cost budget of part 3.
+    pub part3_budget: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part2_budget: 30,
+            part3_budget: 300,
+        }
+    }
+}
+
+impl ParamSet for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "part2_budget" => self.part2_budget = params::parse_value(name, value)?,
+            "part3_budget" => self.part3_budget = params::parse_value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("part2_budget", self.part2_budget.to_string()),
+            ("part3_budget", self.part3_budget.to_string()),
+        ]
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.part2_budget < 0 || self.part3_budget < 0 {
+            return Err("the budgets must not be negative".to_owned());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Item<'a> {
+    code: &'a str,
+    quality: i64,
+    cost: i64,
+    material: i64,
+}
+
+type Data<'a> = Vec<Item<'a>>;
+
+fn parse_input(input: &str) -> Result<Data<'_>, ParseError> {
+    let source = Source::new(Problem14::PROBLEM, input);
+    source
+        .lines(input)
+        .map(|line| {
+            let record = source.record(line, &["Quality", "Cost", "Unique Materials"])?;
+            let mut tokens = source.tokens(record.head("an item number")?);
+            tokens.next("an item number")?;
+            let code = tokens.next("an item code")?;
+            tokens.end()?;
+            Ok(Item {
+                code,
+                quality: record.parse("Quality", "a quality")?,
+                cost: record.parse("Cost", "a cost")?,
+                material: record.parse("Unique Materials", "a number of unique materials")?,
+            })
+        })
+        .collect()
+}
+
+/// Writes the items numbered in order.
+fn print_input(data: &Data) -> String {
+    data.iter()
+        .enumerate()
+        .map(|(index, item)| {
+            format!(
+                "{} {} | Quality : {}, Cost : {}, Unique Materials : {}\n",
+                index + 1,
+                item.code,
+                item.quality,
+                item.cost,
+                item.material
+            )
+        })
+        .collect()
+}
+
+/// Writes `size` items.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|number| {
+            format!(
+                "{number} {} | Quality : {}, Cost : {}, Unique Materials : {}\n",
+                rng.word(5, &UPPERCASE[..10]),
+                rng.int(1..=50),
+                rng.int(1..=30),
+                rng.int(1..=30)
+            )
+        })
+        .collect()
+}
+
+fn rank(a: &Item, b: &Item) -> Ordering {
+    match a.quality.cmp(&b.quality) {
+        Ordering::Equal => a.cost.cmp(&b.cost),
+        otherwise => otherwise,
+    }
+}
+
+fn solve_part1(data: &Data) -> i64 {
+    let mut items = data.clone();
+    items.sort_by(rank);
+    items
+        .into_iter()
+        .rev()
+        .take(5)
+        .map(|item| item.material)
+        .sum()
+}
+
+type Scenario = (usize, i64);
+
+fn optimal_quality(scenario: Scenario, items: &[Item], memory: &mut HashMap<Scenario, i64>) -> i64 {
+    match memory.get(&scenario) {
+        Some(res) => *res,
+        None => {
+            let res = optimal_quality_impl(scenario, items, memory);
+            memory.insert(scenario, res);
+            res
+        }
+    }
+}
+
+fn optimal_quality_impl(
+    (item_count, cost_upper_bound): Scenario,
+    items: &[Item],
+    memory: &mut HashMap<Scenario, i64>,
+) -> i64 {
+    if item_count == 0 || cost_upper_bound == 0 {
+        return 0;
+    }
+
+    let prev = optimal_quality((item_count - 1, cost_upper_bound), items, memory);
+
+    let item_cost = items[item_count - 1].cost;
+    if item_cost > cost_upper_bound {
+        prev
+    } else {
+        let other = optimal_quality(
+            (item_count - 1, cost_upper_bound - item_cost),
+            items,
+            memory,
+        );
+
+        prev.max(other + items[item_count - 1].quality)
+    }
+}
+
+fn optimal_set(
+    (item_count, cost_upper_bound): Scenario,
+    items: &[Item],
+    memory: &HashMap<Scenario, i64>,
+) -> Vec<usize> {
+    if item_count == 0 || cost_upper_bound == 0 {
+        return vec![];
+    }
+
+    if memory
+        .get(&(item_count, cost_upper_bound))
+        .copied()
+        .unwrap()
+        > memory
+            .get(&(item_count - 1, cost_upper_bound))
+            .copied()
+            .unwrap_or_default()
+    {
+        let mut res = optimal_set(
+            (
+                item_count - 1,
+                cost_upper_bound - items[item_count - 1].cost,
+            ),
+            items,
+            memory,
+        );
+        res.push(item_count - 1);
+        res
+    } else {
+        optimal_set((item_count - 1, cost_upper_bound), items, memory)
+    }
+}
+
+fn optimal_synthesis(cost_upper_bound: i64, items: &[Item]) -> i64 {
+    let scenario = (items.len(), cost_upper_bound);
+
+    let mut memory = HashMap::new();
+    let optimal_quality = optimal_quality(scenario, items, &mut memory);
+    let optimal_set = optimal_set(scenario, items, &memory);
+
+    let optimal_material_quantity = optimal_set
+        .into_iter()
+        .map(|i| items[i].material)
+        .sum::<i64>();
+    optimal_material_quantity * optimal_quality
+}
+
+fn solve_part2(data: &Data, params: &Params) -> i64 {
+    optimal_synthesis(params.part2_budget, data)
+}
+
+fn solve_part3(data: &Data, params: &Params) -> i64 {
+    optimal_synthesis(params.part3_budget, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::with_overrides;
+
+    const EXAMPLE: &str = "\
+1 BEACG | Quality : 5, Cost : 9, Unique Materials : 1
+2 BEBJD | Quality : 5, Cost : 9, Unique Materials : 28
+3 BHAFI | Quality : 27, Cost : 9, Unique Materials : 20
+4 CAIDB | Quality : 11, Cost : 9, Unique Materials : 2
+5 CDEEI | Quality : 49, Cost : 7, Unique Materials : 10
+6 HICEF | Quality : 2, Cost : 9, Unique Materials : 2
+7 AAIID | Quality : 33, Cost : 16, Unique Materials : 8
+8 HBGHI | Quality : 26, Cost : 17, Unique Materials : 10
+9 DDFDC | Quality : 26, Cost : 12, Unique Materials : 2
+10 CABEG | Quality : 11, Cost : 2, Unique Materials : 3
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 50);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            solve_part2(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            3955
+        );
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(
+            solve_part3(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            16770
+        );
+    }
+
+    #[test]
+    fn budgets_are_parameters() {
+        let params = with_overrides::<Params>(&[("part2_budget".to_owned(), "300".to_owned())]);
+        assert_eq!(
+            solve_part2(&parse_input(EXAMPLE).unwrap(), &params.unwrap()),
+            16770
+        );
+    }
+
+    #[test]
+    fn budget_spent_exactly() {
+        let input = "\
+1 AAAAA | Quality : 5, Cost : 10, Unique Materials : 1
+2 BBBBB | Quality : 6, Cost : 10, Unique Materials : 2
+3 CCCCC | Quality : 50, Cost : 30, Unique Materials : 3
+";
+        assert_eq!(
+            solve_part2(&parse_input(input).unwrap(), &Params::default()),
+            150
+        );
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
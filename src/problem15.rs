@@ -0,0 +1,344 @@
+use crate::{
+    Answer, ParseError, Solution, View,
+    generate::{LETTERS, Rng},
+    params::{self, ParamSet},
+    parse::Source,
+};
+use std::{fmt::Display, ops::Deref};
+
+pub struct Problem15;
+
+impl Solution for Problem15 {
+    const PROBLEM: u8 = 15;
+
+    type Data<'a> = Data<'a>;
+
+    type Params = Params;
+
+    const VIEWS: &'static [View<Self>] = &[View {
+        name: "tree",
+        about: "the tree of the artifacts, the lower identifiers first under each node",
+        show: show_tree,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part2(data, params).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+pub struct Params {
+    /// The id whose path from the root is described in part 2.
+    pub id: Id,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { id: 500000 }
+    }
+}
+
+impl ParamSet for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "id" => self.id = params::parse_value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Vec<(&'static str, String)> {
+        vec![("id", self.id.to_string())]
+    }
+}
+
+type Id = u64;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Artifact<'a> {
+    name: &'a str,
+    id: Id,
+}
+
+impl Display for Artifact<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} | {}", self.name, self.id)
+    }
+}
+
+type Data<'a> = (Vec<Artifact<'a>>, [Artifact<'a>; 2]);
+
+fn parse_input<'a>(input: &'a str) -> Result<Data<'a>, ParseError> {
+    let source = Source::new(Problem15::PROBLEM, input);
+    let [artifacts, tail] = source.sections(["a section of artifacts", "two artifacts"])?;
+
+    let parse = |line: &'a str| {
+        let (name, id) = source.split_once(line, "|", "an artifact `NAME | ID`")?;
+        Ok(Artifact {
+            name,
+            id: source.parse(id, "an identifier")?,
+        })
+    };
+
+    let tail = {
+        let mut lines = source.lines(tail);
+        let mut next = || match lines.next() {
+            Some(line) => parse(line),
+            None => Err(source.missing(tail, "an artifact")),
+        };
+        let pair = [next()?, next()?];
+        if let Some(line) = lines.next() {
+            return Err(source.error(line, "the end of the input"));
+        }
+        pair
+    };
+
+    let artifacts = source
+        .lines(artifacts)
+        .map(parse)
+        .collect::<Result<_, _>>()?;
+
+    Ok((artifacts, tail))
+}
+
+fn print_input((artifacts, tail): &Data) -> String {
+    let mut input: String = artifacts
+        .iter()
+        .map(|artifact| format!("{artifact}\n"))
+        .collect();
+    input += &format!("\n{}\n{}\n", tail[0], tail[1]);
+    input
+}
+
+/// Writes `size` artifacts and the two artifacts to compare, all with distinct identifiers.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut ids = vec![];
+    while ids.len() < size.max(1) + 2 {
+        let id = rng.int(1..=1_000_000);
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    let tail = ids.split_off(ids.len() - 2);
+    let mut artifact = |id| format!("{} | {id}\n", rng.word(7, LETTERS));
+    let mut input: String = ids.into_iter().map(&mut artifact).collect();
+    input.push('\n');
+    input.extend(tail.into_iter().map(artifact));
+    input
+}
+
+#[derive(Debug)]
+struct TreeNode<'a> {
+    value: Artifact<'a>,
+    left: Option<Box<TreeNode<'a>>>,
+    right: Option<Box<TreeNode<'a>>>,
+}
+
+impl<'a> TreeNode<'a> {
+    fn new(value: Artifact<'a>) -> Self {
+        Self {
+            value,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+fn make_tree<'a>(mut artifacts: impl Iterator<Item = Artifact<'a>>) -> TreeNode<'a> {
+    let mut result = TreeNode::new(artifacts.next().unwrap());
+
+    for artifact in artifacts {
+        let mut node = &mut result;
+        loop {
+            let subtree = if node.value.id < artifact.id {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+            match subtree {
+                Some(next_node) => {
+                    node = next_node;
+                }
+                None => {
+                    *subtree = Some(Box::new(TreeNode::new(artifact)));
+                    break;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Writes each artifact of the tree on its own line, under its parent, with `<` before those with
+/// a lower identifier than it and `>` before the others.
+fn show_tree(data: &Data, _: &Params) -> String {
+    fn write(node: &TreeNode, depth: usize, mark: &str, text: &mut String) {
+        text.push_str(&format!(
+            "{:indent$}{mark}{} {}\n",
+            "",
+            node.value.name,
+            node.value.id,
+            indent = 2 * depth
+        ));
+        for (subtree, mark) in [(&node.right, "< "), (&node.left, "> ")] {
+            if let Some(child) = subtree {
+                write(child, depth + 1, mark, text);
+            }
+        }
+    }
+
+    let mut text = String::new();
+    write(&make_tree(data.0.iter().copied()), 0, "", &mut text);
+    text
+}
+
+fn solve_part1(data: &Data) -> Id {
+    let tree = make_tree(data.0.iter().copied());
+
+    let mut layer = vec![&tree];
+    let mut layer_count = 0;
+    let mut max_layer_value = 0;
+    while !layer.is_empty() {
+        layer_count += 1;
+        max_layer_value = max_layer_value.max(layer.iter().map(|&node| node.value.id).sum());
+        layer = layer
+            .into_iter()
+            .flat_map(|node| {
+                [&node.right, &node.left]
+                    .into_iter()
+                    .filter_map(|subtree| subtree.as_ref().map(|node| node.deref()))
+            })
+            .collect();
+    }
+    max_layer_value * layer_count
+}
+
+fn solve_part2(data: &Data, params: &Params) -> String {
+    let tree = make_tree(data.0.iter().copied());
+
+    let mut node = &tree;
+    let mut result = node.value.name.to_owned();
+
+    loop {
+        let subtree = if node.value.id < params.id {
+            &node.left
+        } else {
+            &node.right
+        };
+        match subtree {
+            Some(next_node) => {
+                result += &format!("-{}", next_node.value.name);
+                node = next_node;
+            }
+            None => break,
+        }
+    }
+
+    result
+}
+
+fn solve_part3<'a>(data: &Data<'a>) -> &'a str {
+    let tree = make_tree(data.0.iter().copied());
+
+    let ancestors: Vec<_> = data
+        .1
+        .iter()
+        .map(|artifact| {
+            let mut result = vec![tree.value.name];
+
+            let mut node = &tree;
+            loop {
+                let subtree = if node.value.id < artifact.id {
+                    &node.left
+                } else {
+                    &node.right
+                };
+                match subtree {
+                    Some(next_node) => {
+                        result.push(next_node.value.name);
+                        node = next_node;
+                    }
+                    None => break,
+                }
+            }
+
+            result
+        })
+        .collect();
+
+    ancestors[0]
+        .iter()
+        .copied()
+        .zip(ancestors[1].iter().copied())
+        .take_while(|(a, b)| a == b)
+        .last()
+        .unwrap()
+        .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+GfeEqeq | 378230
+pnoDFye | 110013
+Escmejv | 394913
+qtiaEdF | 876423
+rgnFssD | 473313
+DDhmtfE | 585659
+bsDeGCr | 53248
+ynnefjq | 658262
+xiGrhxo | 19756
+FFzbkaF | 656647
+CztjAwy | 557260
+uhvauvz | 713729
+
+xiGrhxo | 19756
+CztjAwy | 557260
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 9592632);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            solve_part2(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            "GfeEqeq-Escmejv-qtiaEdF-rgnFssD-DDhmtfE-CztjAwy"
+        );
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), "GfeEqeq");
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
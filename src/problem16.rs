@@ -0,0 +1,782 @@
+use crate::{
+    Answer, ParseError, Solution, checked,
+    generate::Rng,
+    grid::Grid,
+    params::{self, ParamSet},
+    parse::{Source, Tokens},
+    trace,
+};
+use std::{
+    array::from_fn,
+    fmt::{self, Display},
+};
+
+pub struct Problem16;
+
+impl Solution for Problem16 {
+    const PROBLEM: u8 = 16;
+
+    type Data<'a> = Data;
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part1(data, params).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part2(data, params).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part3(data, params).into()
+    }
+}
+
+pub struct Params {
+    /// The number of rows and columns of each face of the die.
+    pub size: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { size: 80 }
+    }
+}
+
+impl ParamSet for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "size" => self.size = params::parse_value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Vec<(&'static str, String)> {
+        vec![("size", self.size.to_string())]
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.size == 0 {
+            return Err("parameter `size` must be positive".to_owned());
+        }
+        Ok(())
+    }
+}
+
+type Value = u8;
+
+type Index = usize;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Locus {
+    Face,
+    Row(Index),
+    Col(Index),
+}
+
+#[derive(PartialEq, Debug)]
+struct Instruction {
+    locus: Locus,
+    value: Value,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.locus {
+            Locus::Face => write!(f, "FACE")?,
+            Locus::Row(index) => write!(f, "ROW {index}")?,
+            Locus::Col(index) => write!(f, "COL {index}")?,
+        }
+        write!(f, " - VALUE {}", self.value)
+    }
+}
+
+#[derive(PartialEq, Debug)]
+enum Twist {
+    Left,
+    Right,
+    Down,
+    Up,
+}
+
+impl Display for Twist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Twist::Left => "L",
+            Twist::Right => "R",
+            Twist::Down => "D",
+            Twist::Up => "U",
+        })
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Data {
+    instructions: Vec<Instruction>,
+    twists: Vec<Twist>,
+}
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem16::PROBLEM, input);
+    let [instructions, twists] =
+        source.sections(["a section of instructions", "a line of twists"])?;
+
+    let instructions = source
+        .lines(instructions)
+        .map(|line| {
+            let record = source.record(line, &["VALUE"])?;
+            let mut tokens = source.tokens(record.head("FACE, ROW or COL")?);
+            let locus_token = tokens.next("FACE, ROW or COL")?;
+            let locus = match locus_token {
+                "FACE" => Locus::Face,
+                "ROW" => Locus::Row(parse_index(&source, &mut tokens)?),
+                "COL" => Locus::Col(parse_index(&source, &mut tokens)?),
+                _ => return Err(source.error(locus_token, "FACE, ROW or COL")),
+            };
+            tokens.keyword("-")?;
+            tokens.end()?;
+            let value = record.parse("VALUE", "a value")?;
+            Ok(Instruction { locus, value })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let twists = source.symbols(twists, "L, R, D or U", |c| match c {
+        'L' => Some(Twist::Left),
+        'R' => Some(Twist::Right),
+        'D' => Some(Twist::Down),
+        'U' => Some(Twist::Up),
+        _ => None,
+    })?;
+
+    Ok(Data {
+        instructions,
+        twists,
+    })
+}
+
+fn print_input(data: &Data) -> String {
+    let mut input: String = data
+        .instructions
+        .iter()
+        .map(|instruction| format!("{instruction}\n"))
+        .collect();
+    input.push('\n');
+    input.extend(data.twists.iter().map(Twist::to_string));
+    input.push('\n');
+    input
+}
+
+/// Writes `size` instructions, at least two, and a twist between each of them.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(2);
+    let mut input = String::new();
+    for _ in 0..count {
+        let locus = match rng.index(3) {
+            0 => "FACE".to_owned(),
+            1 => format!("ROW {}", rng.int(1..=80)),
+            _ => format!("COL {}", rng.int(1..=80)),
+        };
+        input += &format!("{locus} - VALUE {}\n", rng.int(1..=100));
+    }
+    input.push('\n');
+    input += &rng.word(count - 1, b"LRDU");
+    input.push('\n');
+    input
+}
+
+fn parse_index(source: &Source, tokens: &mut Tokens) -> Result<Index, ParseError> {
+    let token = tokens.next("an index")?;
+    match source.parse(token, "an index")? {
+        0 => Err(source.error(token, "a positive index")),
+        index => Ok(index),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Position {
+    Front,
+    Back,
+    Left,
+    Right,
+    Down,
+    Up,
+}
+
+const POSITIONS: [Position; 6] = [
+    Position::Front,
+    Position::Back,
+    Position::Left,
+    Position::Right,
+    Position::Down,
+    Position::Up,
+];
+
+type Absorption = u64;
+
+struct SimpleDie {
+    size: usize,
+    faces: [Absorption; 6],
+}
+
+impl SimpleDie {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            faces: Default::default(),
+        }
+    }
+
+    fn apply(&mut self, instruction: &Instruction) {
+        let cell_count = match instruction.locus {
+            Locus::Face => checked::mul(self.size, self.size),
+            Locus::Row(_) => self.size,
+            Locus::Col(_) => self.size,
+        } as Absorption;
+        let face = &mut self.faces[Position::Front as usize];
+        *face = checked::add(
+            *face,
+            checked::mul(cell_count, instruction.value as Absorption),
+        );
+    }
+
+    fn rotate(&mut self, twist: &Twist) {
+        match twist {
+            Twist::Left => {
+                self.swap(Position::Front, Position::Left);
+                self.swap(Position::Left, Position::Back);
+                self.swap(Position::Back, Position::Right);
+            }
+            Twist::Right => {
+                self.swap(Position::Front, Position::Right);
+                self.swap(Position::Right, Position::Back);
+                self.swap(Position::Back, Position::Left);
+            }
+            Twist::Down => {
+                self.swap(Position::Front, Position::Down);
+                self.swap(Position::Down, Position::Back);
+                self.swap(Position::Back, Position::Up);
+            }
+            Twist::Up => {
+                self.swap(Position::Front, Position::Up);
+                self.swap(Position::Up, Position::Back);
+                self.swap(Position::Back, Position::Down);
+            }
+        }
+    }
+
+    fn swap(&mut self, a: Position, b: Position) {
+        self.faces.swap(a as usize, b as usize);
+    }
+}
+
+/// Checks that the rows and columns of the instructions are on a die face of the size of `params`.
+fn check_indices(data: &Data, params: &Params) -> Result<(), String> {
+    let outside = data.instructions.iter().find(|instruction| {
+        matches!(instruction.locus, Locus::Row(index) | Locus::Col(index) if index > params.size)
+    });
+    match outside {
+        Some(instruction) => Err(format!(
+            "the instruction `{instruction}` is outside a die face of size {}",
+            params.size
+        )),
+        None => Ok(()),
+    }
+}
+
+fn solve_part1(data: &Data, params: &Params) -> Result<Absorption, String> {
+    check_indices(data, params)?;
+    let mut die = SimpleDie::new(params.size);
+
+    let mut instructions = data.instructions.iter();
+    die.apply(instructions.next().unwrap());
+
+    for (instruction, twist) in instructions.zip(data.twists.iter()) {
+        die.rotate(twist);
+        die.apply(instruction);
+    }
+
+    let mut absorptions: Vec<_> = die.faces.into_iter().collect();
+    absorptions.sort_unstable();
+    Ok(checked::product(absorptions.into_iter().rev().take(2)))
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Orientation {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Orientation {
+    fn turn_upside_down(&mut self) {
+        *self = match self {
+            Orientation::Up => Orientation::Down,
+            Orientation::Right => Orientation::Left,
+            Orientation::Down => Orientation::Up,
+            Orientation::Left => Orientation::Right,
+        };
+    }
+
+    fn turn_clockwise(&mut self) {
+        *self = match self {
+            Orientation::Up => Orientation::Right,
+            Orientation::Right => Orientation::Down,
+            Orientation::Down => Orientation::Left,
+            Orientation::Left => Orientation::Up,
+        };
+    }
+
+    fn turn_counterclockwise(&mut self) {
+        *self = match self {
+            Orientation::Up => Orientation::Left,
+            Orientation::Right => Orientation::Up,
+            Orientation::Down => Orientation::Right,
+            Orientation::Left => Orientation::Down,
+        };
+    }
+}
+
+struct Action {
+    locus: Locus,
+    value: Value,
+}
+
+impl Action {
+    /// Turns `instruction`, given for a face seen upright, into one on a face of `size` rows
+    /// and columns seen with `orientation`.
+    fn new(instruction: &Instruction, orientation: Orientation, size: usize) -> Self {
+        let locus = match orientation {
+            Orientation::Up => instruction.locus,
+            Orientation::Right => match instruction.locus {
+                Locus::Face => Locus::Face,
+                Locus::Row(index) => Locus::Col(index),
+                Locus::Col(index) => Locus::Row(size + 1 - index),
+            },
+            Orientation::Down => match instruction.locus {
+                Locus::Face => Locus::Face,
+                Locus::Row(index) => Locus::Row(size + 1 - index),
+                Locus::Col(index) => Locus::Col(size + 1 - index),
+            },
+            Orientation::Left => match instruction.locus {
+                Locus::Face => Locus::Face,
+                Locus::Row(index) => Locus::Col(size + 1 - index),
+                Locus::Col(index) => Locus::Row(index),
+            },
+        };
+        Self {
+            locus,
+            value: instruction.value,
+        }
+    }
+}
+
+impl Grid<Value> {
+    /// A die face of `size` rows and columns, all at 1.
+    fn new(size: usize) -> Self {
+        Grid::filled(size, size, 1)
+    }
+
+    fn size(&self) -> usize {
+        self.row_count()
+    }
+
+    fn apply(&mut self, action: &Action) {
+        match action.locus {
+            Locus::Face => {
+                for entry in self.iter_mut() {
+                    *entry = add(*entry, action.value);
+                }
+            }
+            Locus::Row(index) => {
+                for entry in self.row_mut(index - 1) {
+                    *entry = add(*entry, action.value);
+                }
+            }
+            Locus::Col(index) => {
+                for entry in self.col_mut(index - 1) {
+                    *entry = add(*entry, action.value);
+                }
+            }
+        }
+    }
+
+    fn dominant_sum(&self) -> u64 {
+        let dominant_row_sum = self
+            .rows()
+            .map(|row| checked::sum(row.iter().map(|&v| v as u64)))
+            .max()
+            .unwrap();
+        let dominant_col_sum = self
+            .cols()
+            .map(|col| checked::sum(col.map(|&v| v as u64)))
+            .max()
+            .unwrap();
+        dominant_row_sum.max(dominant_col_sum)
+    }
+}
+
+fn add(a: Value, b: Value) -> Value {
+    checked::sub(checked::add(a, b), 1) % 100 + 1
+}
+
+#[derive(PartialEq, Debug)]
+struct Die {
+    values: [Grid<Value>; 6],
+    orientations: [Orientation; 6],
+}
+
+impl Die {
+    fn new(size: usize) -> Self {
+        Self {
+            values: from_fn(|_| Grid::new(size)),
+            orientations: [Orientation::Up; 6],
+        }
+    }
+
+    fn apply(&mut self, instruction: &Instruction) {
+        let grid = &mut self.values[Position::Front as usize];
+        let action = Action::new(
+            instruction,
+            self.orientations[Position::Front as usize],
+            grid.size(),
+        );
+        grid.apply(&action);
+    }
+
+    fn apply_with_wrap(&mut self, instruction: &Instruction) {
+        let (faces, fixup) = match instruction.locus {
+            Locus::Face => (vec![Position::Front], false),
+            Locus::Row(_) => (
+                vec![
+                    Position::Front,
+                    Position::Right,
+                    Position::Back,
+                    Position::Left,
+                ],
+                true,
+            ),
+            Locus::Col(_) => (
+                vec![
+                    Position::Front,
+                    Position::Down,
+                    Position::Back,
+                    Position::Up,
+                ],
+                false,
+            ),
+        };
+        if fixup {
+            self.orientations[Position::Back as usize].turn_upside_down();
+        }
+        for face in faces {
+            let index = face as usize;
+            let grid = &mut self.values[index];
+            let action = Action::new(instruction, self.orientations[index], grid.size());
+            grid.apply(&action);
+        }
+        if fixup {
+            self.orientations[Position::Back as usize].turn_upside_down();
+        }
+        trace::emit("apply_with_wrap", || {
+            vec![
+                ("instruction", format!("{instruction:?}")),
+                ("die", self.to_string()),
+            ]
+        });
+    }
+
+    fn rotate(&mut self, twist: &Twist) {
+        match twist {
+            Twist::Left => {
+                self.orientations[Position::Back as usize].turn_upside_down();
+                self.swap(Position::Front, Position::Left);
+                self.swap(Position::Left, Position::Back);
+                self.swap(Position::Back, Position::Right);
+                self.orientations[Position::Down as usize].turn_clockwise();
+                self.orientations[Position::Up as usize].turn_counterclockwise();
+                self.orientations[Position::Back as usize].turn_upside_down();
+            }
+            Twist::Right => {
+                self.orientations[Position::Back as usize].turn_upside_down();
+                self.swap(Position::Front, Position::Right);
+                self.swap(Position::Right, Position::Back);
+                self.swap(Position::Back, Position::Left);
+                self.orientations[Position::Down as usize].turn_counterclockwise();
+                self.orientations[Position::Up as usize].turn_clockwise();
+                self.orientations[Position::Back as usize].turn_upside_down();
+            }
+            Twist::Down => {
+                self.swap(Position::Front, Position::Down);
+                self.swap(Position::Down, Position::Back);
+                self.swap(Position::Back, Position::Up);
+                self.orientations[Position::Left as usize].turn_counterclockwise();
+                self.orientations[Position::Right as usize].turn_clockwise();
+            }
+            Twist::Up => {
+                self.swap(Position::Front, Position::Up);
+                self.swap(Position::Up, Position::Back);
+                self.swap(Position::Back, Position::Down);
+                self.orientations[Position::Left as usize].turn_clockwise();
+                self.orientations[Position::Right as usize].turn_counterclockwise();
+            }
+        }
+        trace::emit("rotate", || {
+            vec![("twist", format!("{twist:?}")), ("die", self.to_string())]
+        });
+    }
+
+    fn swap(&mut self, a: Position, b: Position) {
+        self.values.swap(a as usize, b as usize);
+        self.orientations.swap(a as usize, b as usize);
+    }
+}
+
+/// Writes each face with its position and orientation, then its values as stored.
+impl Display for Die {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for position in POSITIONS {
+            let index = position as usize;
+            writeln!(f, "{position:?} ({:?})", self.orientations[index])?;
+            write!(f, "{}", self.values[index])?;
+        }
+        Ok(())
+    }
+}
+
+fn solve_part2(data: &Data, params: &Params) -> Result<u128, String> {
+    check_indices(data, params)?;
+    let mut die = Die::new(params.size);
+
+    let mut instructions = data.instructions.iter();
+    die.apply(instructions.next().unwrap());
+
+    for (instruction, twist) in instructions.zip(data.twists.iter()) {
+        die.rotate(twist);
+        die.apply(instruction);
+    }
+
+    Ok(checked::product(
+        die.values.iter().map(|face| face.dominant_sum() as u128),
+    ))
+}
+
+fn solve_part3(data: &Data, params: &Params) -> Result<u128, String> {
+    check_indices(data, params)?;
+    let mut die = Die::new(params.size);
+
+    let mut instructions = data.instructions.iter();
+    die.apply(instructions.next().unwrap());
+
+    for (instruction, twist) in instructions.zip(data.twists.iter()) {
+        die.rotate(twist);
+        die.apply_with_wrap(instruction);
+    }
+
+    Ok(checked::product(
+        die.values.iter().map(|face| face.dominant_sum() as u128),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+FACE - VALUE 65
+ROW 29 - VALUE 71
+ROW 58 - VALUE 43
+ROW 71 - VALUE 18
+FACE - VALUE 32
+FACE - VALUE 23
+ROW 12 - VALUE 72
+ROW 48 - VALUE 31
+ROW 26 - VALUE 73
+FACE - VALUE 96
+ROW 53 - VALUE 50
+COL 27 - VALUE 68
+
+DUULDUURRLU
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            solve_part1(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            Ok(248679782400)
+        );
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            solve_part2(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            Ok(8676699549990912000000)
+        );
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(
+            solve_part3(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            Ok(46999332815858431946223)
+        );
+    }
+
+    fn marked_die() -> Die {
+        let mut die = Die::new(Params::default().size);
+        for (value, twist) in (1..).zip([
+            Twist::Up,
+            Twist::Up,
+            Twist::Up,
+            Twist::Up,
+            Twist::Left,
+            Twist::Left,
+            Twist::Left,
+            Twist::Down,
+            Twist::Right,
+        ]) {
+            die.apply(&Instruction {
+                locus: Locus::Row(1),
+                value,
+            });
+            die.apply(&Instruction {
+                locus: Locus::Col(2),
+                value: value + 20,
+            });
+            die.rotate(&twist);
+        }
+        die
+    }
+
+    #[test]
+    fn four_identical_twists_are_identity() {
+        for twist in [Twist::Left, Twist::Right, Twist::Down, Twist::Up] {
+            let mut die = marked_die();
+            for _ in 0..4 {
+                die.rotate(&twist);
+            }
+            assert_eq!(die, marked_die());
+        }
+    }
+
+    #[test]
+    fn opposite_twists_cancel() {
+        for (twist, opposite) in [
+            (Twist::Left, Twist::Right),
+            (Twist::Right, Twist::Left),
+            (Twist::Down, Twist::Up),
+            (Twist::Up, Twist::Down),
+        ] {
+            let mut die = marked_die();
+            die.rotate(&twist);
+            die.rotate(&opposite);
+            assert_eq!(die, marked_die());
+        }
+    }
+
+    #[test]
+    fn wrapped_row_matches_rows_applied_face_by_face() {
+        let instruction = Instruction {
+            locus: Locus::Row(3),
+            value: 7,
+        };
+
+        let mut wrapped = marked_die();
+        wrapped.apply_with_wrap(&instruction);
+
+        let mut face_by_face = marked_die();
+        for _ in 0..4 {
+            face_by_face.apply(&instruction);
+            face_by_face.rotate(&Twist::Left);
+        }
+
+        assert_eq!(wrapped, face_by_face);
+    }
+
+    #[test]
+    fn wrapped_col_matches_cols_applied_face_by_face() {
+        let instruction = Instruction {
+            locus: Locus::Col(5),
+            value: 11,
+        };
+
+        let mut wrapped = marked_die();
+        wrapped.apply_with_wrap(&instruction);
+
+        let mut face_by_face = marked_die();
+        for _ in 0..4 {
+            face_by_face.apply(&instruction);
+            face_by_face.rotate(&Twist::Up);
+        }
+
+        assert_eq!(wrapped, face_by_face);
+    }
+
+    #[test]
+    fn actions_match_instructions_on_the_face_as_seen() {
+        // A face seen with an orientation is the stored one turned that many quarters clockwise.
+        let size = 5;
+        let face = Grid::from_fn(size, size, |[row, col]| (row * size + col) as Value + 1);
+        for (orientation, quarters) in [
+            (Orientation::Up, 0),
+            (Orientation::Right, 1),
+            (Orientation::Down, 2),
+            (Orientation::Left, 3),
+        ] {
+            for locus in [Locus::Face, Locus::Row(2), Locus::Col(4)] {
+                let instruction = Instruction { locus, value: 30 };
+
+                let mut stored = face.clone();
+                stored.apply(&Action::new(&instruction, orientation, size));
+
+                let mut seen = (0..quarters).fold(face.clone(), |face, _| face.rotate_clockwise());
+                seen.apply(&Action::new(&instruction, Orientation::Up, size));
+                let seen = (0..quarters).fold(seen, |face, _| face.rotate_counterclockwise());
+
+                assert_eq!(seen, stored);
+            }
+        }
+    }
+
+    #[test]
+    fn index_outside_a_smaller_die() {
+        let data = parse_input(EXAMPLE).unwrap();
+        let params = Params { size: 70 };
+        let outside = "the instruction `ROW 71 - VALUE 18` is outside a die face of size 70";
+        assert_eq!(check_indices(&data, &params), Err(outside.to_owned()));
+        assert_eq!(solve_part1(&data, &params).unwrap_err(), outside);
+        assert_eq!(solve_part2(&data, &params).unwrap_err(), outside);
+        assert_eq!(solve_part3(&data, &params).unwrap_err(), outside);
+        assert!(solve_part2(&data, &Params { size: 71 }).is_ok());
+    }
+
+    #[test]
+    fn unknown_twist() {
+        let Err(err) = parse_input("FACE - VALUE 1\nROW 2 - VALUE 3\n\nLRXU\n") else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!((err.line, err.column), (4, 3));
+        assert_eq!(err.token, "X");
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
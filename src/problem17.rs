@@ -0,0 +1,602 @@
+use crate::{
+    Answer, ParseError, Solution, View, checked,
+    generate::Rng,
+    params::{self, ParamSet},
+    parse::Source,
+    search,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, hash_map},
+    fmt::Display,
+    iter::once,
+    num::NonZero,
+};
+
+pub struct Problem17;
+
+impl Solution for Problem17 {
+    const PROBLEM: u8 = 17;
+
+    type Data<'a> = Data;
+
+    type Params = Params;
+
+    const VIEWS: &'static [View<Self>] = &[View {
+        name: "successors",
+        about: "the nodes reachable in one allowed move from each node",
+        show: show_successors,
+    }];
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part3(data, params).into()
+    }
+}
+
+pub struct Params {
+    /// The rank, among the allowed paths in order, of the path described in part 3.
+    pub target_path_rank: PathCount,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            target_path_rank: 100000000000000000000000000000,
+        }
+    }
+}
+
+impl ParamSet for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "target_path_rank" => self.target_path_rank = params::parse_value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Vec<(&'static str, String)> {
+        vec![("target_path_rank", self.target_path_rank.to_string())]
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.target_path_rank == 0 {
+            return Err("parameter `target_path_rank` starts at 1".to_owned());
+        }
+        Ok(())
+    }
+}
+
+type StaircaseId = usize;
+
+type StepRank = u8;
+
+type StepCount = u8;
+
+type PathCount = u128;
+
+#[derive(PartialEq, Debug)]
+struct Staircase {
+    begin: StepRank,
+    end: StepRank,
+    feeding_staircase: Option<StaircaseId>,
+    return_staircase: Option<StaircaseId>,
+}
+
+/// The line of the staircase after its name, which is given by its position.
+impl Display for Staircase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let staircase = |id: Option<StaircaseId>, terminal| match id {
+            Some(id) => format!("S{id}"),
+            None => terminal,
+        };
+        write!(
+            f,
+            "{} -> {} : FROM {} TO {}",
+            self.begin,
+            self.end,
+            staircase(self.feeding_staircase, "START".to_owned()),
+            staircase(self.return_staircase, "END".to_owned())
+        )
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Data {
+    staircases: Vec<Staircase>,
+    allowed_moves: Vec<StepCount>,
+}
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem17::PROBLEM, input);
+    let [staircases, moves] =
+        source.sections(["a section of staircases", "a line of possible moves"])?;
+
+    let staircase_count = source.lines(staircases).count();
+    let staircase_id = |token: &str, terminal: &str| -> Result<Option<StaircaseId>, ParseError> {
+        let expected = format!("{terminal} or a staircase `S1` to `S{staircase_count}`");
+        if token == terminal {
+            return Ok(None);
+        }
+        match token
+            .strip_prefix('S')
+            .map(|id| source.parse(id, &expected))
+        {
+            Some(Ok(id @ 1..)) if id <= staircase_count => Ok(Some(id)),
+            _ => Err(source.error(token, expected)),
+        }
+    };
+
+    let staircases = source
+        .lines(staircases)
+        .map(|line| {
+            let record = source.record(line, &["FROM", "TO"])?;
+            let (_, steps) = source.split_once(
+                record.head("a staircase name")?,
+                ":",
+                "a staircase `NAME : BEGIN -> END`",
+            )?;
+            let (begin, end) = source.split_once(steps, "->", "steps `BEGIN -> END`")?;
+            let begin = source.parse(begin, "a step rank")?;
+            let end = source.parse(end, "a step rank")?;
+            let feeding_staircase = staircase_id(record.field("FROM", "a staircase")?, "START")?;
+            let return_staircase = staircase_id(record.field("TO", "a staircase")?, "END")?;
+
+            Ok(Staircase {
+                begin,
+                end,
+                feeding_staircase,
+                return_staircase,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let record = source.record(moves, &["Possible Moves"])?;
+    record.headless()?;
+    let allowed_moves = source
+        .integers::<NonZero<StepCount>>(
+            record.field("Possible Moves", "a positive step count")?,
+            "a positive step count",
+        )?
+        .into_iter()
+        .map(NonZero::get)
+        .collect();
+
+    Ok(Data {
+        staircases,
+        allowed_moves,
+    })
+}
+
+fn print_input(data: &Data) -> String {
+    let mut input: String = data
+        .staircases
+        .iter()
+        .enumerate()
+        .map(|(index, staircase)| format!("S{} : {staircase}\n", index + 1))
+        .collect();
+    let moves: Vec<_> = data.allowed_moves.iter().map(u8::to_string).collect();
+    input += &format!("\nPossible Moves : {}\n", moves.join(", "));
+    input
+}
+
+/// Writes a first staircase of 20 to 50 steps, `size` - 1 branches strictly inside the staircases
+/// they leave and return to, and moves of 1 to 3 steps, always including 1.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let step_count = rng.int(20..=50);
+    let mut staircases = vec![(0, step_count)];
+    let mut input = format!("S1 : 0 -> {step_count} : FROM START TO END\n");
+    for id in 2..=size.max(1) {
+        let feeding: Vec<_> = (0..staircases.len())
+            .filter(|&k| staircases[k].0 + 1 < staircases[k].1.min(step_count - 1))
+            .collect();
+        let feeding = *rng.choose(&feeding);
+        let begin =
+            rng.int(staircases[feeding].0 + 1..=staircases[feeding].1.min(step_count - 1) - 1);
+        let end = rng.int(begin + 1..=(begin + 10).min(step_count - 1));
+        let returning: Vec<_> = (0..staircases.len())
+            .filter(|&k| staircases[k].0 < end && end < staircases[k].1)
+            .collect();
+        let returning = *rng.choose(&returning);
+        input += &format!(
+            "S{id} : {begin} -> {end} : FROM S{} TO S{}\n",
+            feeding + 1,
+            returning + 1
+        );
+        staircases.push((begin, end));
+    }
+    let moves: Vec<_> = once(1)
+        .chain((2..=3).filter(|_| rng.index(2) == 0))
+        .map(|step| step.to_string())
+        .collect();
+    input + &format!("\nPossible Moves : {}\n", moves.join(", "))
+}
+
+fn path_count(step_count: StepCount, allowed_moves: &[StepCount]) -> PathCount {
+    let mut memory = vec![vec![0; allowed_moves.len() + 1]; step_count as usize + 1];
+    memory[0].fill(1);
+
+    for (i, c) in (1..=step_count).map(|c| (c as usize, c)) {
+        for (j, m) in allowed_moves
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(j, m)| (j + 1, m))
+        {
+            let with_move = if m > c {
+                0
+            } else {
+                let k = (c - m) as usize;
+                checked::sum((0..=k).map(|n| checked::mul(memory[n][j - 1], memory[k - n][j])))
+            };
+            memory[i][j] = checked::add(memory[i][j - 1], with_move);
+        }
+    }
+
+    *memory.last().unwrap().last().unwrap()
+}
+
+fn solve_part1(data: &Data) -> PathCount {
+    path_count(
+        data.staircases[0].end - data.staircases[0].begin,
+        &data.allowed_moves,
+    )
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+struct Node {
+    staircase_id: StaircaseId,
+    step_rank: StepRank,
+}
+
+impl Node {
+    fn new(staircase_id: StaircaseId, step_rank: StepRank) -> Self {
+        Self {
+            staircase_id,
+            step_rank,
+        }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "S{}_{}", self.staircase_id, self.step_rank)
+    }
+}
+
+fn nodes_to_explore(staircases: &[Staircase]) -> Vec<Node> {
+    let max_step_rank = staircases[0].end - staircases[0].begin;
+
+    let mut res = vec![];
+
+    let mut active_staircases = BTreeSet::<StaircaseId>::new();
+
+    for step_rank in 0..=max_step_rank {
+        let mut next_active_staircases = BTreeSet::new();
+
+        for id in active_staircases.iter().copied().rev() {
+            let s = &staircases[id - 1];
+            if s.end == step_rank {
+                res.push(Node::new(id, step_rank));
+            } else {
+                next_active_staircases.insert(id);
+            }
+        }
+
+        for id in next_active_staircases.iter().copied() {
+            res.push(Node::new(id, step_rank));
+        }
+
+        for (id, s) in staircases.iter().enumerate().map(|(id, s)| (id + 1, s)) {
+            if s.begin == step_rank {
+                res.push(Node::new(id, step_rank));
+                next_active_staircases.insert(id);
+            }
+        }
+
+        active_staircases = next_active_staircases;
+    }
+
+    res
+}
+
+fn feeding_branches(staircases: &[Staircase]) -> Vec<HashMap<StaircaseId, Vec<StaircaseId>>> {
+    let max_step_rank = staircases[0].end - staircases[0].begin;
+
+    (0..=max_step_rank)
+        .map(|step_rank| {
+            let mut branches = HashMap::new();
+
+            for (id, s) in staircases.iter().enumerate().map(|(idx, s)| (idx + 1, s)) {
+                if s.begin == step_rank
+                    && let Some(return_id) = s.feeding_staircase
+                {
+                    match branches.entry(return_id) {
+                        hash_map::Entry::Vacant(vacant_entry) => vacant_entry.insert_entry(vec![]),
+                        hash_map::Entry::Occupied(occupied_entry) => occupied_entry,
+                    }
+                    .get_mut()
+                    .push(id);
+                }
+            }
+
+            branches
+        })
+        .collect()
+}
+
+/// The nodes one step away from `node`: up its staircase, back onto the staircase it returns to
+/// at its end, or onto the branches it feeds at this rank.
+fn steps(
+    staircases: &[Staircase],
+    branches: &[HashMap<StaircaseId, Vec<StaircaseId>>],
+    node: Node,
+) -> Vec<Node> {
+    let mut res = vec![];
+    let staircase = &staircases[node.staircase_id - 1];
+    if staircase.end != node.step_rank {
+        res.push(Node::new(node.staircase_id, node.step_rank + 1));
+    } else if let Some(return_id) = staircase.return_staircase {
+        res.push(Node::new(return_id, node.step_rank));
+    }
+    if let Some(feeder_ids) = branches[node.step_rank as usize].get(&node.staircase_id) {
+        res.extend(
+            feeder_ids
+                .iter()
+                .map(|&returner_id| Node::new(returner_id, node.step_rank)),
+        );
+    }
+    res
+}
+
+fn successors(staircases: &[Staircase], allowed_moves: &[StepCount]) -> HashMap<Node, Vec<Node>> {
+    let branches = feeding_branches(staircases);
+    let max_step_size = *allowed_moves.iter().max().unwrap();
+
+    nodes_to_explore(staircases)
+        .into_iter()
+        .rev()
+        .map(|node| {
+            // A node may be reached with different numbers of steps, which are all kept apart.
+            let reached = search::bfs(
+                (node, 0),
+                |&(node, step_size): &(Node, StepCount)| {
+                    let next = if step_size < max_step_size {
+                        steps(staircases, &branches, node)
+                    } else {
+                        vec![]
+                    };
+                    next.into_iter().map(move |next| (next, step_size + 1))
+                },
+                |_| false,
+            );
+            let res: BTreeSet<_> = reached
+                .costs
+                .into_keys()
+                .filter(|(_, step_size)| allowed_moves.contains(step_size))
+                .map(|(node, _)| node)
+                .collect();
+            (node, res.into_iter().collect())
+        })
+        .collect()
+}
+
+fn show_successors(data: &Data, _: &Params) -> String {
+    let successors: BTreeMap<_, _> = successors(&data.staircases, &data.allowed_moves)
+        .into_iter()
+        .collect();
+    successors
+        .into_iter()
+        .map(|(node, next)| {
+            let next: Vec<_> = next.iter().map(Node::to_string).collect();
+            if next.is_empty() {
+                format!("{node} -> nothing\n")
+            } else {
+                format!("{node} -> {}\n", next.join(", "))
+            }
+        })
+        .collect()
+}
+
+fn allowed_starting_paths(
+    staircases: &[Staircase],
+    allowed_moves: &[StepCount],
+) -> HashMap<Node, PathCount> {
+    let successors = successors(staircases, allowed_moves);
+
+    let mut res = HashMap::new();
+
+    let end_node = Node::new(1, staircases[0].end);
+
+    res.insert(end_node, 1);
+
+    for node in nodes_to_explore(staircases).into_iter().rev().skip(1) {
+        let count = checked::sum(
+            successors
+                .get(&node)
+                .unwrap()
+                .iter()
+                .map(|predecessor| *res.get(predecessor).unwrap()),
+        );
+        res.insert(node, count);
+    }
+
+    res
+}
+
+fn solve_part2(data: &Data) -> PathCount {
+    let begin_node = Node::new(1, data.staircases[0].begin);
+
+    *allowed_starting_paths(&data.staircases, &data.allowed_moves)
+        .get(&begin_node)
+        .unwrap()
+}
+
+fn solve_part3(data: &Data, params: &Params) -> String {
+    let successors = successors(&data.staircases, &data.allowed_moves);
+    let allowed_paths = allowed_starting_paths(&data.staircases, &data.allowed_moves);
+
+    let begin_node = Node::new(1, data.staircases[0].begin);
+    let end_node = Node::new(1, data.staircases[0].end);
+
+    let target_path_rank = params
+        .target_path_rank
+        .min(*allowed_paths.get(&begin_node).unwrap());
+
+    let mut path = vec![begin_node];
+    let mut dominated_path_count: PathCount = 0;
+
+    let mut node = *path.last().unwrap();
+    while node != end_node {
+        let (next_node, next_dominated_path_count) = successors[&node]
+            .iter()
+            .zip(
+                successors[&node]
+                    .iter()
+                    .scan(dominated_path_count, |acc, x| {
+                        let res = Some(*acc);
+                        *acc = checked::add(*acc, *allowed_paths.get(x).unwrap());
+                        res
+                    }),
+            )
+            .take_while(|(_, n)| *n < target_path_rank)
+            .last()
+            .unwrap();
+
+        dominated_path_count = next_dominated_path_count;
+        path.push(*next_node);
+        node = *path.last().unwrap();
+    }
+
+    path.into_iter()
+        .map(|node| format!("{}", node))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, shrink_int, shrink_vec};
+
+    const EXAMPLE: &str = "\
+S1 : 0 -> 6 : FROM START TO END
+S2 : 2 -> 4 : FROM S1 TO S1
+S3 : 3 -> 5 : FROM S2 TO S1
+
+Possible Moves : 1, 2
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 102);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(
+            solve_part3(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            "S1_0-S1_2-S2_3-S3_4-S3_5-S1_6"
+        );
+    }
+
+    #[test]
+    fn successors_follow_staircases_and_branches() {
+        let data = parse_input(EXAMPLE).unwrap();
+        let successors = successors(&data.staircases, &data.allowed_moves);
+        assert_eq!(
+            successors[&Node::new(1, 0)],
+            [Node::new(1, 1), Node::new(1, 2)]
+        );
+        assert_eq!(
+            successors[&Node::new(1, 2)],
+            [
+                Node::new(1, 3),
+                Node::new(1, 4),
+                Node::new(2, 2),
+                Node::new(2, 3)
+            ]
+        );
+        assert_eq!(
+            successors[&Node::new(2, 4)],
+            [Node::new(1, 4), Node::new(1, 5)]
+        );
+        assert_eq!(
+            successors[&Node::new(3, 5)],
+            [Node::new(1, 5), Node::new(1, 6)]
+        );
+    }
+
+    /// Counts the paths one by one, trying every move from every step.
+    fn brute_force_path_count(step_count: StepCount, allowed_moves: &[StepCount]) -> PathCount {
+        if step_count == 0 {
+            return 1;
+        }
+        allowed_moves
+            .iter()
+            .filter(|&&step| step <= step_count)
+            .map(|&step| brute_force_path_count(step_count - step, allowed_moves))
+            .sum()
+    }
+
+    #[test]
+    fn path_count_matches_brute_force() {
+        let shrink_step = |&step: &StepCount| {
+            shrink_int(step as i64, 1)
+                .into_iter()
+                .map(|step| step as StepCount)
+                .collect::<Vec<_>>()
+        };
+        property::check(
+            |rng| {
+                let moves = (0..rng.int(1..=3))
+                    .map(|_| rng.int(1..=4) as StepCount)
+                    .collect::<Vec<_>>();
+                (rng.int(0..=18) as StepCount, moves)
+            },
+            |(step_count, moves)| {
+                let fewer_steps = shrink_int(*step_count as i64, 0)
+                    .into_iter()
+                    .map(|step_count| (step_count as StepCount, moves.clone()));
+                let fewer_moves = shrink_vec(moves, shrink_step)
+                    .into_iter()
+                    .filter(|moves| !moves.is_empty())
+                    .map(|moves| (*step_count, moves));
+                fewer_steps.chain(fewer_moves).collect()
+            },
+            |(step_count, moves)| {
+                path_count(*step_count, moves) == brute_force_path_count(*step_count, moves)
+            },
+        );
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
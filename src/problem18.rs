@@ -0,0 +1,531 @@
+use crate::{
+    Answer, ParseError, Solution,
+    generate::Rng,
+    params::{self, ParamSet},
+    parse::Source,
+    search, trace,
+};
+use std::{array::from_fn, fmt::Display, iter::once};
+
+pub struct Problem18;
+
+impl Solution for Problem18 {
+    const PROBLEM: u8 = 18;
+
+    type Data<'a> = Data;
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part1(data, params).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part2(data, params).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part3(data, params).into()
+    }
+}
+
+pub struct Params {
+    /// The number of positions along x, y, z and a.
+    pub size: [Coord; 4],
+    /// The position of the origin, where the journey starts and debris cannot hit.
+    pub offset: [Coord; 4],
+    /// The number of hits that are fatal in part 3.
+    pub life: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            size: [10, 15, 60, 3],
+            offset: [0, 0, 0, 1],
+            life: 4,
+        }
+    }
+}
+
+impl ParamSet for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "size" => self.size = params::parse_array(name, value)?,
+            "offset" => self.offset = params::parse_array(name, value)?,
+            "life" => self.life = params::parse_value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("size", params::format_array(&self.size)),
+            ("offset", params::format_array(&self.offset)),
+            ("life", self.life.to_string()),
+        ]
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.size.iter().zip(&self.offset).any(|(&s, &o)| o >= s) {
+            return Err("each coordinate of `offset` must be less than the `size`".to_owned());
+        }
+        if self.life == 0 {
+            return Err("parameter `life` must be positive".to_owned());
+        }
+        Ok(())
+    }
+}
+
+impl Params {
+    /// The position of the exit: the far corner in x, y and z, at the origin in a.
+    fn exit(&self) -> Position {
+        [
+            self.size[0] - 1,
+            self.size[1] - 1,
+            self.size[2] - 1,
+            self.offset[3],
+        ]
+    }
+
+    /// The number of steps after which every debris is back where it started.
+    fn period(&self) -> usize {
+        fn gcd(a: usize, b: usize) -> usize {
+            if b == 0 { a } else { gcd(b, a % b) }
+        }
+        self.size.iter().fold(1, |period, &size| {
+            period / gcd(period, size as usize) * size as usize
+        })
+    }
+}
+
+type Coord = u8;
+type SignedCoord = i8;
+
+type Position = [Coord; 4];
+type Velocity = [SignedCoord; 4];
+
+type Factor = u8;
+
+type Time = u16;
+
+#[derive(PartialEq, Debug)]
+pub struct Rule {
+    factors: [Factor; 4],
+    divisor: Factor,
+    remainder: Factor,
+    velocity: Velocity,
+}
+
+/// The line of the rule after its number, which is given by its position.
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [x, y, z, a] = self.factors;
+        let [vx, vy, vz, va] = self.velocity;
+        write!(
+            f,
+            "{x}x+{y}y+{z}z+{a}a DIVIDE {} HAS REMAINDER {} | DEBRIS VELOCITY ({vx}, {vy}, {vz}, {va})",
+            self.divisor, self.remainder
+        )
+    }
+}
+
+impl Rule {
+    fn holds(&self, time: usize, position: &Position, params: &Params) -> bool {
+        self.value(time, position, params) == self.remainder as u64
+    }
+
+    /// The remainder of the division at `position` and `time`, which must be the rule's for the
+    /// rule to hold.
+    fn value(&self, time: usize, position: &Position, params: &Params) -> u64 {
+        let sum = position
+            .iter()
+            .zip(params.offset.iter().zip(params.size.iter()))
+            .zip(self.velocity.iter())
+            .map(|((&p, (&o, &s)), &v)| {
+                let p0 = p as i64 - (time as i64 * v as i64);
+                let p = if p0 >= 0 {
+                    p0 as u64 % s as u64
+                } else {
+                    let n = (-p0 + s as i64) / s as i64;
+                    (p0 + (n * s as i64)) as u64 % s as u64
+                };
+                (p as Coord, o)
+            })
+            .zip(self.factors.iter())
+            .map(|((p, o), &f)| (p as i64 - o as i64) * (f as i64))
+            .sum::<i64>();
+        (sum + self.divisor as i64) as u64 % (self.divisor as u64)
+    }
+
+    fn holds_for_initial_time(&self, position: &Position, params: &Params) -> bool {
+        self.holds(0, position, params)
+    }
+}
+
+fn position_is_safe(position: &Position, time: usize, rules: &[Rule], params: &Params) -> bool {
+    position == &params.offset || !rules.iter().any(|rule| rule.holds(time, position, params))
+}
+
+fn safe_successors(
+    position: &Position,
+    time: usize,
+    rules: &[Rule],
+    params: &Params,
+) -> Vec<Position> {
+    (0..3)
+        .flat_map(|i| {
+            [
+                if position[i] > 0 { Some(-1) } else { None },
+                if position[i] + 1 < params.size[i] {
+                    Some(1)
+                } else {
+                    None
+                },
+            ]
+            .into_iter()
+            .filter_map(move |d| {
+                d.map(|d| {
+                    let mut res = *position;
+                    res[i] = (res[i] as SignedCoord + d) as Coord;
+                    res
+                })
+            })
+        })
+        .chain(once(position).copied())
+        .filter(|candidate| position_is_safe(candidate, time + 1, rules, params))
+        .collect()
+}
+
+fn hits_sustained(position: &Position, time: usize, rules: &[Rule], params: &Params) -> usize {
+    if position == &params.offset {
+        0
+    } else {
+        rules
+            .iter()
+            .filter(|rule| rule.holds(time, position, params))
+            .count()
+    }
+}
+
+fn successors(
+    position: &Position,
+    time: usize,
+    rules: &[Rule],
+    params: &Params,
+) -> Vec<(Position, usize)> {
+    (0..3)
+        .flat_map(|i| {
+            [
+                if position[i] > 0 { Some(-1) } else { None },
+                if position[i] + 1 < params.size[i] {
+                    Some(1)
+                } else {
+                    None
+                },
+            ]
+            .into_iter()
+            .filter_map(move |d| {
+                d.map(|d| {
+                    let mut res = *position;
+                    res[i] = (res[i] as SignedCoord + d) as Coord;
+                    res
+                })
+            })
+        })
+        .chain(once(position).copied())
+        .map(|position| (position, hits_sustained(&position, time + 1, rules, params)))
+        .collect()
+}
+
+type Data = Vec<Rule>;
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem18::PROBLEM, input);
+    source
+        .lines(input)
+        .map(|line| {
+            let record = source.record(
+                line,
+                &["RULE", "DIVIDE", "HAS REMAINDER", "DEBRIS VELOCITY"],
+            )?;
+            record.headless()?;
+            let (_, factors) = source.split_once(
+                record.field("RULE", "a rule number")?,
+                ":",
+                "a rule `RULE N: FACTORS`",
+            )?;
+            let factors = parse_factors(&source, factors)?;
+            let divisor_token = record.field("DIVIDE", "a positive divisor")?;
+            let divisor = match source.parse(divisor_token, "a positive divisor")? {
+                0 => return Err(source.error(divisor_token, "a positive divisor")),
+                divisor => divisor,
+            };
+            let remainder = record.parse("HAS REMAINDER", "a remainder")?;
+            let velocity = source.tuple(
+                record.field("DEBRIS VELOCITY", "a velocity `(X, Y, Z, A)`")?,
+                "a velocity component",
+            )?;
+
+            Ok(Rule {
+                factors,
+                divisor,
+                remainder,
+                velocity,
+            })
+        })
+        .collect()
+}
+
+fn print_input(data: &Data) -> String {
+    data.iter()
+        .enumerate()
+        .map(|(index, rule)| format!("RULE {}: {rule}\n", index + 1))
+        .collect()
+}
+
+/// Writes `size` rules, none of which ever hits the [straight path](straight_path) to the exit
+/// with the default parameters, so that the exit can always be reached.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let params = Params::default();
+    let path = straight_path(&params);
+    (1..=size.max(1))
+        .map(|id| {
+            loop {
+                let mut rule = Rule {
+                    factors: from_fn(|_| rng.int(1..=10) as Factor),
+                    divisor: rng.int(20..=40) as Factor,
+                    remainder: 0,
+                    velocity: from_fn(|_| rng.int(-1..=1) as SignedCoord),
+                };
+                let hits: Vec<u64> = path
+                    .iter()
+                    .map(|(time, position)| rule.value(*time, position, &params))
+                    .collect();
+                let missed: Vec<Factor> = (0..rule.divisor)
+                    .filter(|&remainder| !hits.contains(&(remainder as u64)))
+                    .collect();
+                // Rules that hit the path whatever their remainder are drawn again.
+                if !missed.is_empty() {
+                    rule.remainder = *rng.choose(&missed);
+                    break format!("RULE {id}: {rule}\n");
+                }
+            }
+        })
+        .collect()
+}
+
+/// The path from the origin to the exit along x, then y, then z, one step at a time, with the
+/// time each position is reached.
+fn straight_path(params: &Params) -> Vec<(usize, Position)> {
+    let exit = params.exit();
+    let mut position = params.offset;
+    let mut path = vec![];
+    for axis in 0..3 {
+        while position[axis] < exit[axis] {
+            position[axis] += 1;
+            path.push((path.len() + 1, position));
+        }
+    }
+    path
+}
+
+/// Parses the factors of the four variables, such as `2x+9y+9z+1a`.
+fn parse_factors(source: &Source, text: &str) -> Result<[Factor; 4], ParseError> {
+    let factors = text
+        .split('+')
+        .map(str::trim_ascii)
+        .map(|term| match term.char_indices().last() {
+            Some((i, c)) if c.is_ascii_alphabetic() => {
+                source.parse(term[..i].trim_ascii(), "a factor")
+            }
+            _ => Err(source.error(term, "a factor followed by a variable")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    factors
+        .try_into()
+        .map_err(|_| source.error(text, "4 factors separated by `+`"))
+}
+
+fn solve_part1(data: &Data, params: &Params) -> usize {
+    data.iter()
+        .map(|rule| {
+            let [x_size, y_size, z_size, a_size] = params.size;
+            (0..x_size)
+                .flat_map(|x| (0..y_size).map(move |y| (x, y)))
+                .flat_map(|(x, y)| (0..z_size).map(move |z| (x, y, z)))
+                .flat_map(|p| (0..a_size).map(move |a| [p.0, p.1, p.2, a]))
+                .filter(|p| rule.holds_for_initial_time(p, params))
+                .count()
+        })
+        .sum()
+}
+
+/// Traces the number of states of each frontier of a breadth-first search over states that
+/// include the phase of their time, which is the same for every state of a frontier.
+#[derive(Default)]
+struct Frontier {
+    time: usize,
+    phase: usize,
+    states: usize,
+}
+
+impl Frontier {
+    fn visit(&mut self, phase: usize) {
+        if phase != self.phase {
+            trace::emit("frontier", || {
+                vec![
+                    ("time", self.time.to_string()),
+                    ("states", self.states.to_string()),
+                ]
+            });
+            *self = Frontier {
+                time: self.time + 1,
+                phase,
+                states: 0,
+            };
+        }
+        self.states += 1;
+    }
+}
+
+/// The time the exit is reached at, from the cost of the goal of a search whose costs are times.
+fn exit_time(cost: Option<usize>) -> Result<Time, String> {
+    let time = cost.ok_or("the exit cannot be reached")?;
+    Time::try_from(time)
+        .map_err(|_| format!("the exit cannot be reached within {} steps", Time::MAX))
+}
+
+/// Debris are back where they started after a [period](Params::period), so a state is a position
+/// and the time within the period.
+fn solve_part2(data: &Data, params: &Params) -> Result<Time, String> {
+    let exit = params.exit();
+    let period = params.period();
+    let mut frontier = Frontier::default();
+    let search = search::bfs(
+        (params.offset, 0),
+        |&(position, phase): &(Position, usize)| {
+            frontier.visit(phase);
+            let next_phase = (phase + 1) % period;
+            safe_successors(&position, phase, data, params)
+                .into_iter()
+                .map(move |next| (next, next_phase))
+        },
+        |&(position, _)| position == exit,
+    );
+    exit_time(search.goal_cost())
+}
+
+/// Like the second part, with the number of hits sustained so far as the label of the state:
+/// reaching a position at the same time within the period, as early, with fewer hits, is better.
+fn solve_part3(data: &Data, params: &Params) -> Result<Time, String> {
+    let exit = params.exit();
+    let period = params.period();
+    let mut frontier = Frontier::default();
+    let search = search::labelled_bfs(
+        ((params.offset, 0), 0),
+        |&((position, phase), hits): &((Position, usize), usize)| {
+            frontier.visit(phase);
+            let next_phase = (phase + 1) % period;
+            successors(&position, phase, data, params)
+                .into_iter()
+                .map(move |(next, new_hits)| ((next, next_phase), hits + new_hits))
+                .filter(|&(_, hits)| hits < params.life)
+        },
+        |&((position, _), _)| position == exit,
+    );
+    exit_time(search.goal_cost())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+RULE 1: 7x+7y+1z+5a DIVIDE 12 HAS REMAINDER 6 | DEBRIS VELOCITY (0, 0, 0, 1)
+RULE 2: 4x+9y+3z+5a DIVIDE 7 HAS REMAINDER 6 | DEBRIS VELOCITY (-1, 1, 0, 1)
+RULE 3: 10x+3y+5z+2a DIVIDE 6 HAS REMAINDER 5 | DEBRIS VELOCITY (0, 0, 1, -1)
+RULE 4: 6x+7y+6z+10a DIVIDE 8 HAS REMAINDER 7 | DEBRIS VELOCITY (0, 1, 0, -1)
+";
+
+    use crate::property;
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            solve_part1(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            13757
+        );
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            solve_part2(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            Ok(84)
+        );
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(
+            solve_part3(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            Ok(82)
+        );
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        let params = Params::default();
+        let path = straight_path(&params);
+        assert_eq!(
+            path.last().map(|&(_, position)| position),
+            Some(params.exit())
+        );
+        property::check(
+            |rng| parse_input(&generate_input(rng, 50)).unwrap(),
+            |_| vec![],
+            |data| {
+                data.iter().all(|rule| {
+                    path.iter()
+                        .all(|(time, position)| !rule.holds(*time, position, &params))
+                })
+            },
+        );
+        let data = parse_input(&generate_input(&mut Rng::new(0), 4)).unwrap();
+        // A path without any hit is also one for the third part.
+        assert!(solve_part2(&data, &params).is_ok());
+    }
+
+    #[test]
+    fn unreachable_exit() {
+        // Debris hit every position but the origin at all times.
+        let data = parse_input(
+            "RULE 1: 0x+0y+0z+0a DIVIDE 1 HAS REMAINDER 0 | DEBRIS VELOCITY (0, 0, 0, 0)\n",
+        )
+        .unwrap();
+        let params = Params::default();
+        let unreachable = Err("the exit cannot be reached".to_owned());
+        assert_eq!(solve_part2(&data, &params), unreachable);
+        assert_eq!(solve_part3(&data, &params), unreachable);
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
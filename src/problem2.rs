@@ -0,0 +1,303 @@
+use crate::{
+    Answer, ParseError, Solution, checked,
+    generate::Rng,
+    params::{self, ParamSet},
+    parse::Source,
+};
+use std::fmt::Display;
+
+pub struct Problem2;
+
+impl Solution for Problem2 {
+    const PROBLEM: u8 = 2;
+
+    type Data<'a> = Data;
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, params: &Self::Params) -> Answer {
+        solve_part3(data, params).into()
+    }
+}
+
+pub struct Params {
+    /// The highest price part 3 can afford.
+    pub upper_bound: Int,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            upper_bound: 15000000000000,
+        }
+    }
+}
+
+impl ParamSet for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "upper_bound" => self.upper_bound = params::parse_value(name, value)?,
+            _ => return Err(params::unknown(name)),
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Vec<(&'static str, String)> {
+        vec![("upper_bound", self.upper_bound.to_string())]
+    }
+}
+
+type Int = i64;
+
+#[derive(PartialEq, Debug)]
+enum Op {
+    Plus,
+    Mult,
+    Pow,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Func {
+    op: Op,
+    value: Int,
+}
+
+/// The operation of the function, as written after its name.
+impl Display for Func {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.op {
+            Op::Plus => write!(f, "ADD {}", self.value),
+            Op::Mult => write!(f, "MULTIPLY {}", self.value),
+            Op::Pow => write!(f, "RAISE TO THE POWER OF {}", self.value),
+        }
+    }
+}
+
+impl Func {
+    fn apply(&self, i: Int) -> Int {
+        match self.op {
+            Op::Plus => checked::add(i, self.value),
+            Op::Mult => checked::mul(i, self.value),
+            Op::Pow => checked::pow(i, self.value as u32),
+        }
+    }
+}
+
+type Data = (Vec<Func>, Vec<Int>);
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem2::PROBLEM, input);
+    let [funcs, prices] = source.sections(["a section of functions", "a section of prices"])?;
+
+    let funcs = source
+        .lines(funcs)
+        .map(|line| {
+            let (name, operation) =
+                source.split_once(line, ":", "a function `Function NAME: OPERATION`")?;
+            let mut tokens = source.tokens(name);
+            tokens.keyword("Function")?;
+            tokens.next("a function name")?;
+            tokens.end()?;
+
+            let mut tokens = source.tokens(operation);
+            let op_token = tokens.next("an operation")?;
+            let op = match op_token {
+                "ADD" => Op::Plus,
+                "MULTIPLY" => Op::Mult,
+                "RAISE" => {
+                    for keyword in ["TO", "THE", "POWER", "OF"] {
+                        tokens.keyword(keyword)?;
+                    }
+                    Op::Pow
+                }
+                _ => return Err(source.error(op_token, "ADD, MULTIPLY or RAISE")),
+            };
+            let value = match op {
+                Op::Pow => {
+                    let exponent: u32 = tokens.parse("a non-negative exponent")?;
+                    exponent as Int
+                }
+                _ => tokens.parse("an integer")?,
+            };
+            tokens.end()?;
+            Ok(Func { op, value })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let values = source
+        .lines(prices)
+        .map(|line| source.parse(line, "a price"))
+        .collect::<Result<_, _>>()?;
+
+    Ok((funcs, values))
+}
+
+/// Writes the functions, named `A`, `B`, `C`... in order, then the prices.
+fn print_input((funcs, prices): &Data) -> String {
+    let mut input = String::new();
+    for (index, func) in funcs.iter().enumerate() {
+        let name = char::from(b'A' + (index % 26) as u8);
+        input += &format!("Function {name}: {func}\n");
+    }
+    input.push('\n');
+    input.extend(prices.iter().map(|price| format!("{price}\n")));
+    input
+}
+
+/// Writes `size` prices, low enough for the sum of the even ones to go through the functions
+/// without overflowing.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(1);
+    let max_price = (400_000 / count as Int).clamp(1, 5_000);
+    let mut input = format!(
+        "Function A: ADD {}\nFunction B: MULTIPLY {}\nFunction C: RAISE TO THE POWER OF 3\n\n",
+        rng.int(1..=1_000),
+        rng.int(2..=100)
+    );
+    for _ in 0..count {
+        input += &format!("{}\n", rng.int(1..=max_price));
+    }
+    input
+}
+
+fn solve_part1(data: &Data) -> Int {
+    let mut prices: Vec<_> = data.1.to_vec();
+    prices.sort();
+    let median_price = prices[prices.len() / 2];
+    apply(median_price, &data.0)
+}
+
+fn solve_part2(data: &Data) -> Int {
+    let even_prices = data.1.iter().copied().filter(|i| i % 2 == 0).sum();
+    apply(even_prices, &data.0)
+}
+
+fn solve_part3(data: &Data, params: &Params) -> Result<Int, String> {
+    let mut data: Vec<_> = data
+        .1
+        .iter()
+        .copied()
+        .map(|i| (i, apply(i, &data.0)))
+        .collect();
+    data.sort_unstable_by_key(|item| item.1);
+    data.iter()
+        .rev()
+        .find(|&(_, i)| *i <= params.upper_bound)
+        .map(|&(i, _)| i)
+        .ok_or_else(|| {
+            format!(
+                "no quality is affordable within the upper bound {}",
+                params.upper_bound
+            )
+        })
+}
+
+fn apply(price: Int, funcs: &[Func]) -> Int {
+    funcs.iter().rev().fold(price, |acc, f| f.apply(acc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Function A: ADD 495
+Function B: MULTIPLY 55
+Function C: RAISE TO THE POWER OF 3
+
+5219
+8933
+3271
+7128
+9596
+9407
+7005
+1607
+9671
+3267
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 19918918863855);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 257266457328815);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(
+            solve_part3(&parse_input(EXAMPLE).unwrap(), &Params::default()),
+            Ok(5219)
+        );
+    }
+
+    #[test]
+    fn nothing_affordable() {
+        let params = Params { upper_bound: 0 };
+        assert_eq!(
+            solve_part3(&parse_input(EXAMPLE).unwrap(), &params),
+            Err("no quality is affordable within the upper bound 0".to_owned())
+        );
+    }
+
+    #[test]
+    fn unknown_operation() {
+        let Err(err) = parse_input("Function A: ADD 495\nFunction B: DIVIDE 3\n\n5219\n") else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!((err.line, err.column), (2, 13));
+        assert_eq!(err.token, "DIVIDE");
+    }
+
+    #[test]
+    fn negative_exponent() {
+        let Err(err) = parse_input("Function A: RAISE TO THE POWER OF -2\n\n5219\n") else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!((err.line, err.column), (1, 35));
+        assert_eq!(err.token, "-2");
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    #[should_panic(expected = "arithmetic overflow computing 10 ^ 20 at src/problem2.rs")]
+    fn overflow_is_reported() {
+        apply(
+            10,
+            &[Func {
+                op: Op::Pow,
+                value: 20,
+            }],
+        );
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
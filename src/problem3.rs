@@ -0,0 +1,193 @@
+use crate::{Answer, ParseError, Solution, generate::Rng, parse::Source};
+use std::ops::RangeInclusive;
+
+pub struct Problem3;
+
+impl Solution for Problem3 {
+    const PROBLEM: u8 = 3;
+
+    type Data<'a> = Data;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+type Int = i16;
+
+type Data = Vec<[RangeInclusive<Int>; 2]>;
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem3::PROBLEM, input);
+    let parse_range = |token| -> Result<RangeInclusive<Int>, ParseError> {
+        let bounds = source.split_once(token, "-", "a range `A-B`")?;
+        Ok(source.parse(bounds.0, "a lower bound")?..=source.parse(bounds.1, "an upper bound")?)
+    };
+    source
+        .lines(input)
+        .map(|line| {
+            let mut tokens = source.tokens(line);
+            let ranges = [
+                parse_range(tokens.next("a range `A-B`")?)?,
+                parse_range(tokens.next("a second range `A-B`")?)?,
+            ];
+            tokens.end()?;
+            Ok(ranges)
+        })
+        .collect()
+}
+
+fn print_input(data: &Data) -> String {
+    data.iter()
+        .map(|[a, b]| format!("{}-{} {}-{}\n", a.start(), a.end(), b.start(), b.end()))
+        .collect()
+}
+
+/// Writes `size` pairs of ranges, at least two.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.int(0..=10_000);
+        format!("{start}-{}", rng.int(start..=start + 1_000))
+    };
+    (0..size.max(2))
+        .map(|_| format!("{} {}\n", range(), range()))
+        .collect()
+}
+
+fn solve_part1(data: &Data) -> usize {
+    data.iter()
+        .flat_map(|pair| pair.iter().map(|rng| rng.len()))
+        .sum()
+}
+
+fn solve_part2(data: &Data) -> usize {
+    data.iter()
+        .map(|pair| {
+            (*pair[0].start().min(pair[1].start())..=*pair[0].end().max(pair[1].end())).len()
+                - (*pair[0].end().min(pair[1].end()) + 1..*pair[0].start().max(pair[1].start()))
+                    .len()
+        })
+        .sum()
+}
+
+fn solve_part3(data: &Data) -> usize {
+    data.windows(2)
+        .map(|w| {
+            let mut ranges: Vec<_> = w.iter().flat_map(|p| p.iter().cloned()).collect();
+            ranges.sort_unstable_by_key(|rng| *rng.start());
+            ranges[1..]
+                .iter()
+                .fold((ranges[0].len(), *ranges[0].end()), |(len, end), rng| {
+                    (
+                        len + ((end + 1).max(*rng.start())..=*rng.end()).len(),
+                        end.max(*rng.end()),
+                    )
+                })
+                .0
+        })
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, shrink_int, shrink_vec};
+
+    const EXAMPLE: &str = "\
+8-9 9-10
+7-8 8-10
+9-10 5-10
+3-10 9-10
+4-8 7-9
+9-10 2-7
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 43);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 35);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 9);
+    }
+
+    /// Counts the integers in either range one by one.
+    fn brute_force_union(pair: &[RangeInclusive<Int>; 2]) -> usize {
+        let low = *pair[0].start().min(pair[1].start());
+        let high = *pair[0].end().max(pair[1].end());
+        (low..=high)
+            .filter(|x| pair.iter().any(|range| range.contains(x)))
+            .count()
+    }
+
+    /// Ranges moved towards 0, then shortened, never reversed.
+    fn shrink_range(range: &RangeInclusive<Int>) -> Vec<RangeInclusive<Int>> {
+        let (start, end) = (*range.start() as i64, *range.end() as i64);
+        let moved = shrink_int(start, 0)
+            .into_iter()
+            .map(|moved| (moved, moved + end - start));
+        let shortened = shrink_int(end, start).into_iter().map(|end| (start, end));
+        moved
+            .chain(shortened)
+            .map(|(start, end)| start as Int..=end as Int)
+            .collect()
+    }
+
+    #[test]
+    fn part2_matches_brute_force() {
+        property::check(
+            |rng| {
+                let count = rng.int(1..=5);
+                let mut range = || {
+                    let start = rng.int(0..=20);
+                    start as Int..=rng.int(start..=start + 10) as Int
+                };
+                (0..count).map(|_| [range(), range()]).collect::<Data>()
+            },
+            |data| {
+                shrink_vec(data, |pair| {
+                    shrink_vec(pair, shrink_range)
+                        .into_iter()
+                        .filter_map(|pair| pair.try_into().ok())
+                        .collect()
+                })
+            },
+            |data| solve_part2(data) == data.iter().map(brute_force_union).sum(),
+        );
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
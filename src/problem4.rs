@@ -0,0 +1,192 @@
+use crate::{
+    Answer, ParseError, Part, Solution,
+    generate::{Rng, UPPERCASE},
+    parse::Source,
+    stream::{self, StreamError, Streaming},
+};
+use std::{io::BufRead, iter::repeat_n};
+
+pub struct Problem4;
+
+impl Solution for Problem4 {
+    const PROBLEM: u8 = 4;
+
+    type Data<'a> = Data<'a>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+impl Streaming for Problem4 {
+    fn stream(
+        reader: &mut dyn BufRead,
+        _: &Self::Params,
+        parts: &[Part],
+    ) -> Result<Vec<Answer>, StreamError> {
+        let mut totals = vec![0; parts.len()];
+        stream::for_each_line(Self::PROBLEM, reader, |source, line| {
+            let line = parse_line(source, line)?;
+            for (total, &part) in totals.iter_mut().zip(parts) {
+                *total += line_size(line, part);
+            }
+            Ok(())
+        })?;
+        Ok(totals.into_iter().map(Answer::from).collect())
+    }
+}
+
+type Data<'a> = Vec<&'a [u8]>;
+
+fn parse_input(input: &str) -> Result<Data<'_>, ParseError> {
+    let source = Source::new(Problem4::PROBLEM, input);
+    source
+        .lines(input)
+        .map(|line| parse_line(&source, line))
+        .collect()
+}
+
+fn parse_line<'a>(source: &Source<'a>, line: &'a str) -> Result<&'a [u8], ParseError> {
+    let line = source.word(line, "an uppercase letter", |c| c.is_ascii_uppercase())?;
+    Ok(line.as_bytes())
+}
+
+fn print_input(data: &Data) -> String {
+    data.iter()
+        .map(|line| format!("{}\n", String::from_utf8_lossy(line)))
+        .collect()
+}
+
+/// Writes `size` lines made of runs of the same letter.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        for _ in 0..rng.int(1..=20) {
+            let letter = *rng.choose(UPPERCASE) as char;
+            input.extend(repeat_n(letter, rng.index(12) + 1));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn solve_part1(data: &Data) -> i64 {
+    data.iter().map(|line| line_size(line, Part::One)).sum()
+}
+
+fn solve_part2(data: &Data) -> i64 {
+    data.iter().map(|line| line_size(line, Part::Two)).sum()
+}
+
+fn solve_part3(data: &Data) -> i64 {
+    data.iter().map(|line| line_size(line, Part::Three)).sum()
+}
+
+/// The size of one line once compressed as in `part`, each line being compressed on its own.
+fn line_size(line: &[u8], part: Part) -> i64 {
+    match part {
+        Part::One => line.iter().copied().map(alpha_size).sum(),
+        Part::Two => {
+            let length = line.len();
+            let to_keep = length / 10;
+            let to_remove = length - 2 * to_keep;
+            number_size(to_remove)
+                + line
+                    .iter()
+                    .take(to_keep)
+                    .chain(line.iter().rev().take(to_keep))
+                    .copied()
+                    .map(alpha_size)
+                    .sum::<i64>()
+        }
+        Part::Three => {
+            let (total, count) =
+                line.windows(2)
+                    .fold((alpha_size(line[0]), 1), |(total, count), x| {
+                        if x[0] == x[1] {
+                            (total, count + 1)
+                        } else {
+                            (total + number_size(count) + alpha_size(x[1]), 1)
+                        }
+                    });
+            total + number_size(count)
+        }
+    }
+}
+
+fn alpha_size(c: u8) -> i64 {
+    (c - b'A' + 1) as i64
+}
+
+fn number_size(n: usize) -> i64 {
+    let mut res = 0;
+    let mut remainder = n;
+    while remainder > 0 {
+        res += remainder % 10;
+        remainder /= 10;
+    }
+    res as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+NNBUSSSSSS
+LCCKDUDWWW
+JJXXXXXXXXHHHHAAQQ
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 572);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 118);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 232);
+    }
+
+    #[test]
+    fn streamed_answers() {
+        let answers = Problem4::stream(&mut EXAMPLE.as_bytes(), &(), &Part::ALL).unwrap();
+        assert_eq!(
+            answers,
+            [Answer::I64(572), Answer::I64(118), Answer::I64(232)]
+        );
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{Answer, ParseError, Solution, generate::Rng, parse::Source};
+use std::cmp::Ordering;
+
+pub struct Problem5;
+
+impl Solution for Problem5 {
+    const PROBLEM: u8 = 5;
+
+    type Data<'a> = Data;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+type Data = Vec<Pos>;
+
+type Pos = [i64; 2];
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem5::PROBLEM, input);
+    source
+        .lines(input)
+        .map(|line| source.tuple(line, "an integer"))
+        .collect()
+}
+
+fn print_input(data: &Data) -> String {
+    data.iter().map(|[x, y]| format!("({x}, {y})\n")).collect()
+}
+
+/// Writes `size` islands, at least two.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(2))
+        .map(|_| {
+            format!(
+                "({}, {})\n",
+                rng.int(-1_000..=1_000),
+                rng.int(-1_000..=1_000)
+            )
+        })
+        .collect()
+}
+
+fn solve_part1(data: &Data) -> i64 {
+    let minmax_distances = data.iter().fold((i64::MAX, 0), |acc, x| {
+        let d = distance(x, &Pos::default());
+        (acc.0.min(d), acc.1.max(d))
+    });
+    minmax_distances.1 - minmax_distances.0
+}
+
+fn solve_part2(data: &Data) -> i64 {
+    let comparator = Comparator::new(Pos::default());
+    let closest_island = data.iter().min_by(|a, b| comparator.apply(a, b)).unwrap();
+    data.iter()
+        .filter_map(|pos| {
+            if pos == closest_island {
+                None
+            } else {
+                Some(distance(closest_island, pos))
+            }
+        })
+        .min()
+        .unwrap()
+}
+
+fn solve_part3(data: &Data) -> i64 {
+    let mut remainder = data.clone();
+    let mut explored = vec![Pos::default()];
+
+    while !remainder.is_empty() {
+        let comparator = Comparator::new(*explored.last().unwrap());
+        let index = remainder
+            .iter()
+            .enumerate()
+            .min_by(|a, b| comparator.apply(a.1, b.1))
+            .unwrap()
+            .0;
+        explored.push(remainder.swap_remove(index));
+    }
+
+    explored
+        .windows(2)
+        .map(|leg| distance(&leg[0], &leg[1]))
+        .sum()
+}
+
+fn distance(a: &Pos, b: &Pos) -> i64 {
+    a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).sum()
+}
+
+struct Comparator {
+    reference: Pos,
+}
+
+impl Comparator {
+    fn new(reference: Pos) -> Self {
+        Self { reference }
+    }
+
+    fn apply(&self, a: &Pos, b: &Pos) -> Ordering {
+        distance(a, &self.reference)
+            .cmp(&distance(b, &self.reference))
+            .then(a[0].cmp(&b[0]))
+            .then(a[1].cmp(&b[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+(-35, -123)
+(2, 133)
+(-176, -163)
+(74, -152)
+(-13, 98)
+(-171, 59)
+(-91, -181)
+(-156, 22)
+(14, -165)
+(-77, -154)
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 228);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 50);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 1046);
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    Answer, ParseError, Part, Solution,
+    generate::{LETTERS, Rng},
+    parse::Source,
+    stream::{self, StreamError, Streaming},
+};
+use std::io::BufRead;
+
+pub struct Problem6;
+
+impl Solution for Problem6 {
+    const PROBLEM: u8 = 6;
+
+    type Data<'a> = Data<'a>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+impl Streaming for Problem6 {
+    fn stream(
+        reader: &mut dyn BufRead,
+        _: &Self::Params,
+        parts: &[Part],
+    ) -> Result<Vec<Answer>, StreamError> {
+        let mut totals = Totals::default();
+        // The input is trimmed at the end, so whitespace only counts once something follows it.
+        let mut pending = 0;
+        // Only the start of the input is kept, to check it and to show it in an error.
+        let mut start = None;
+        stream::for_each_chunk(reader, |chunk| {
+            start.get_or_insert_with(|| {
+                let line = chunk
+                    .split(|&byte| byte == b'\n')
+                    .next()
+                    .unwrap_or_default();
+                String::from_utf8_lossy(line).into_owned()
+            });
+            for &byte in chunk {
+                if byte.is_ascii_whitespace() {
+                    pending += 1;
+                } else {
+                    for _ in 0..pending {
+                        totals.add(b' ');
+                    }
+                    pending = 0;
+                    totals.add(byte);
+                }
+            }
+        })?;
+        let start = start.unwrap_or_default();
+        let checked = check_start(&Source::new(Self::PROBLEM, &start), &start);
+        if let Err(error) = checked {
+            return Err(StreamError::Parse { error, line: start });
+        }
+        Ok(parts
+            .iter()
+            .map(|part| match part {
+                Part::One => totals.letters,
+                Part::Two => totals.uncorrupted,
+                Part::Three => totals.repaired,
+            })
+            .map(Answer::from)
+            .collect())
+    }
+}
+
+/// The answers to every part over the bytes added so far.
+#[derive(Default)]
+struct Totals {
+    letters: i64,
+    uncorrupted: i64,
+    repaired: i64,
+    previous: i64,
+}
+
+impl Totals {
+    fn add(&mut self, byte: u8) {
+        if byte.is_ascii_alphabetic() {
+            self.letters += 1;
+            self.uncorrupted += uncorrupted_value(byte);
+        }
+        self.previous = repaired_value(byte, self.previous);
+        self.repaired += self.previous;
+    }
+}
+
+type Data<'a> = &'a [u8];
+
+fn parse_input(input: &str) -> Result<Data<'_>, ParseError> {
+    let source = Source::new(Problem6::PROBLEM, input);
+    check_start(&source, input)?;
+    Ok(input.as_bytes().trim_ascii_end())
+}
+
+/// Checks that `text` starts with a letter, since a corrupted character is repaired from the one
+/// before it.
+fn check_start(source: &Source, text: &str) -> Result<(), ParseError> {
+    match text.chars().next() {
+        None => Err(source.missing(text, "a letter")),
+        Some(c) if !c.is_ascii_alphabetic() => Err(source.error(&text[..c.len_utf8()], "a letter")),
+        Some(_) => Ok(()),
+    }
+}
+
+fn print_input(data: &Data) -> String {
+    format!("{}\n", String::from_utf8_lossy(data))
+}
+
+/// Writes a line of `size` printable characters, starting with a letter.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let printable: Vec<_> = (b'!'..=b'~').collect();
+    let mut input = rng.word(1, LETTERS);
+    input += &rng.word(size.max(1) - 1, &printable);
+    input.push('\n');
+    input
+}
+
+fn solve_part1(data: Data) -> i64 {
+    data.iter().filter(|b| b.is_ascii_alphabetic()).count() as i64
+}
+
+fn solve_part2(data: Data) -> i64 {
+    data.iter()
+        .filter(|b| b.is_ascii_alphabetic())
+        .copied()
+        .map(uncorrupted_value)
+        .sum()
+}
+
+fn solve_part3(data: Data) -> i64 {
+    data.iter()
+        .fold((0, 0), |(total, previous), &x| {
+            let value = repaired_value(x, previous);
+            (total + value, value)
+        })
+        .0
+}
+
+fn uncorrupted_value(b: u8) -> i64 {
+    (if b.is_ascii_lowercase() {
+        b - b'a' + 1
+    } else {
+        b - b'A' + 27
+    }) as i64
+}
+
+fn corrupted_value(v: i64) -> i64 {
+    ((v as u8 * 2 + (52 - 6)) % 52 + 1) as i64
+}
+
+/// The value of `b` once repaired, given the value of the character before it.
+fn repaired_value(b: u8, previous: i64) -> i64 {
+    if b.is_ascii_alphabetic() {
+        uncorrupted_value(b)
+    } else {
+        corrupted_value(previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+t#UD$%%DVd*L?^p?S$^@#9,pfL#}
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(parse_input(EXAMPLE).unwrap()), 12);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(parse_input(EXAMPLE).unwrap()), 338);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(parse_input(EXAMPLE).unwrap()), 690);
+    }
+
+    #[test]
+    fn streamed_answers() {
+        let answers = Problem6::stream(&mut EXAMPLE.as_bytes(), &(), &Part::ALL).unwrap();
+        assert_eq!(
+            answers,
+            [Answer::I64(12), Answer::I64(338), Answer::I64(690)]
+        );
+    }
+
+    #[test]
+    fn input_starts_with_a_letter() {
+        let Err(err) = parse_input("#tUD\n") else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 1, "#"));
+        let Err(err) = parse_input("") else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 1, ""));
+
+        let Err(StreamError::Parse { error, line }) =
+            Problem6::stream(&mut " tUD\n".as_bytes(), &(), &Part::ALL)
+        else {
+            panic!("the input should be rejected");
+        };
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (1, 1, " ")
+        );
+        assert_eq!(line, " tUD");
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
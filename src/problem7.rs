@@ -0,0 +1,277 @@
+use crate::{Answer, ParseError, Solution, generate::Rng, parse::Source};
+
+pub struct Problem7;
+
+impl Solution for Problem7 {
+    const PROBLEM: u8 = 7;
+
+    type Data<'a> = Data;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Data {
+    current_frequencies: Vec<i64>,
+    swap_instructions: Vec<(usize, usize)>,
+    test_index: usize,
+}
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let source = Source::new(Problem7::PROBLEM, input);
+    let [frequencies, swaps, test_index] = source.sections([
+        "a section of frequencies",
+        "a section of swaps",
+        "a test index",
+    ])?;
+
+    let current_frequencies: Vec<_> = source
+        .lines(frequencies)
+        .map(|line| source.parse(line, "a frequency"))
+        .collect::<Result<_, _>>()?;
+
+    let swap_instructions = source
+        .lines(swaps)
+        .map(|line| {
+            let tokens = source.split_once(line, "-", "a swap `X-Y`")?;
+            Ok((
+                parse_index(&source, tokens.0, current_frequencies.len())?,
+                parse_index(&source, tokens.1, current_frequencies.len())?,
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let test_index = parse_index(&source, test_index, current_frequencies.len())?;
+
+    Ok(Data {
+        current_frequencies,
+        swap_instructions,
+        test_index,
+    })
+}
+
+fn print_input(data: &Data) -> String {
+    let mut input: String = data
+        .current_frequencies
+        .iter()
+        .map(|frequency| format!("{frequency}\n"))
+        .collect();
+    input.push('\n');
+    input.extend(
+        data.swap_instructions
+            .iter()
+            .map(|(x, y)| format!("{x}-{y}\n")),
+    );
+    input += &format!("\n{}\n", data.test_index);
+    input
+}
+
+/// Writes `size` frequencies and as many swaps.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(1);
+    let mut input = String::new();
+    for _ in 0..count {
+        input += &format!("{}\n", rng.int(1..=1_000));
+    }
+    input.push('\n');
+    for _ in 0..count {
+        input += &format!("{}-{}\n", rng.index(count) + 1, rng.index(count) + 1);
+    }
+    input + &format!("\n{}\n", rng.index(count) + 1)
+}
+
+fn parse_index(source: &Source, token: &str, len: usize) -> Result<usize, ParseError> {
+    let expected = format!("an index between 1 and {len}");
+    match source.parse(token, &expected)? {
+        index @ 1.. if index <= len => Ok(index),
+        _ => Err(source.error(token, expected)),
+    }
+}
+
+fn solve_part1(data: &Data) -> i64 {
+    let mut frequencies = data.current_frequencies.clone();
+    for &swap in data.swap_instructions.iter() {
+        perform_swap(&mut frequencies, swap);
+    }
+    frequencies[data.test_index - 1]
+}
+
+fn solve_part2(data: &Data) -> i64 {
+    let mut frequencies = data.current_frequencies.clone();
+    for swaps in data.swap_instructions.windows(2) {
+        perform_threeway_swap(&mut frequencies, swaps[0], swaps[1]);
+    }
+    perform_threeway_swap(
+        &mut frequencies,
+        *data.swap_instructions.last().unwrap(),
+        data.swap_instructions[0],
+    );
+    frequencies[data.test_index - 1]
+}
+
+fn solve_part3(data: &Data) -> i64 {
+    let mut frequencies = data.current_frequencies.clone();
+    for swap in data.swap_instructions.iter().copied() {
+        let start = swap.0.min(swap.1) - 1;
+        let mid = swap.0.max(swap.1) - 1;
+        let block_length = (frequencies.len() - mid).min(mid - start);
+        let (head, tail) = frequencies[start..].split_at_mut(mid - start);
+        head[..block_length].swap_with_slice(&mut tail[..block_length]);
+    }
+    frequencies[data.test_index - 1]
+}
+
+fn perform_swap(frequencies: &mut [i64], swap: (usize, usize)) {
+    frequencies.swap(swap.0 - 1, swap.1 - 1);
+}
+
+fn perform_threeway_swap(frequencies: &mut [i64], swap1: (usize, usize), swap2: (usize, usize)) {
+    perform_swap(frequencies, swap1);
+    perform_swap(frequencies, (swap1.0, swap2.0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{self, shrink_int, shrink_vec};
+
+    const EXAMPLE: &str = "\
+159
+527
+827
+596
+296
+413
+45
+796
+853
+778
+
+4-8
+5-6
+6-10
+3-9
+7-9
+4-10
+2-10
+6-8
+10-4
+10-1
+
+10
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 159);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 596);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 159);
+    }
+
+    /// Swaps the blocks by reading each value of a new list from where it comes from.
+    fn brute_force_part3(frequencies: &[i64], swaps: &[(usize, usize)]) -> Vec<i64> {
+        let mut frequencies = frequencies.to_vec();
+        for &(x, y) in swaps {
+            let (x, y) = (x.min(y) - 1, x.max(y) - 1);
+            let length = (y - x).min(frequencies.len() - y);
+            frequencies = (0..frequencies.len())
+                .map(|i| {
+                    if (x..x + length).contains(&i) {
+                        frequencies[i - x + y]
+                    } else if (y..y + length).contains(&i) {
+                        frequencies[i - y + x]
+                    } else {
+                        frequencies[i]
+                    }
+                })
+                .collect();
+        }
+        frequencies
+    }
+
+    type Case = (Vec<i64>, Vec<(usize, usize)>);
+
+    fn shrink_case((frequencies, swaps): &Case) -> Vec<Case> {
+        let shrink_index =
+            |&index: &usize| shrink_int(index as i64, 1).into_iter().map(|i| i as usize);
+        let fewer_frequencies = shrink_vec(frequencies, |&value| shrink_int(value, 0))
+            .into_iter()
+            .filter(|frequencies| {
+                !frequencies.is_empty() && swaps.iter().all(|&(x, y)| x.max(y) <= frequencies.len())
+            })
+            .map(|frequencies| (frequencies, swaps.clone()));
+        let fewer_swaps = shrink_vec(swaps, |&(x, y)| {
+            shrink_index(&x)
+                .map(|x| (x, y))
+                .chain(shrink_index(&y).map(|y| (x, y)))
+                .collect()
+        })
+        .into_iter()
+        .map(|swaps| (frequencies.clone(), swaps));
+        fewer_frequencies.chain(fewer_swaps).collect()
+    }
+
+    #[test]
+    fn part3_matches_brute_force() {
+        property::check(
+            |rng| {
+                let count = rng.index(8) + 1;
+                let frequencies = (0..count).map(|_| rng.int(0..=99)).collect();
+                let swaps = (0..rng.int(1..=5))
+                    .map(|_| (rng.index(count) + 1, rng.index(count) + 1))
+                    .collect();
+                (frequencies, swaps)
+            },
+            shrink_case,
+            |(frequencies, swaps)| {
+                let expected = brute_force_part3(frequencies, swaps);
+                (1..=frequencies.len()).all(|test_index| {
+                    let data = Data {
+                        current_frequencies: frequencies.clone(),
+                        swap_instructions: swaps.clone(),
+                        test_index,
+                    };
+                    solve_part3(&data) == expected[test_index - 1]
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    Answer, ParseError, Part, Solution,
+    generate::Rng,
+    parse::Source,
+    stream::{self, StreamError, Streaming},
+};
+use std::io::BufRead;
+
+pub struct Problem8;
+
+impl Solution for Problem8 {
+    const PROBLEM: u8 = 8;
+
+    type Data<'a> = Data<'a>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+impl Streaming for Problem8 {
+    fn stream(
+        reader: &mut dyn BufRead,
+        _: &Self::Params,
+        parts: &[Part],
+    ) -> Result<Vec<Answer>, StreamError> {
+        let mut totals = vec![0; parts.len()];
+        stream::for_each_line(Self::PROBLEM, reader, |source, line| {
+            let line = parse_line(source, line)?;
+            for (total, &part) in totals.iter_mut().zip(parts) {
+                *total += reduced_length(line, part);
+            }
+            Ok(())
+        })?;
+        Ok(totals.into_iter().map(Answer::from).collect())
+    }
+}
+
+type Data<'a> = Vec<&'a [u8]>;
+
+fn parse_input(input: &str) -> Result<Data<'_>, ParseError> {
+    let source = Source::new(Problem8::PROBLEM, input);
+    source
+        .lines(input)
+        .map(|line| parse_line(&source, line))
+        .collect()
+}
+
+fn parse_line<'a>(source: &Source<'a>, line: &'a str) -> Result<&'a [u8], ParseError> {
+    let line = source.word(line, "a letter, a digit or `-`", |c| {
+        c.is_ascii_alphanumeric() || c == '-'
+    })?;
+    Ok(line.as_bytes())
+}
+
+fn print_input(data: &Data) -> String {
+    data.iter()
+        .map(|line| format!("{}\n", String::from_utf8_lossy(line)))
+        .collect()
+}
+
+/// Writes `size` lines of letters, digits and dashes.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789-";
+    (0..size.max(1))
+        .map(|_| {
+            let len = rng.index(40) + 1;
+            rng.word(len, CHARS) + "\n"
+        })
+        .collect()
+}
+
+fn solve_part1(data: &Data) -> i64 {
+    data.iter()
+        .map(|line| reduced_length(line, Part::One))
+        .sum()
+}
+
+fn solve_part2(data: &Data) -> i64 {
+    data.iter()
+        .map(|line| reduced_length(line, Part::Two))
+        .sum()
+}
+
+fn solve_part3(data: &Data) -> i64 {
+    data.iter()
+        .map(|line| reduced_length(line, Part::Three))
+        .sum()
+}
+
+/// The length of one line once reduced as in `part`, each line being reduced on its own.
+fn reduced_length(line: &[u8], part: Part) -> i64 {
+    match part {
+        Part::One => line.iter().copied().filter(u8::is_ascii_alphabetic).count() as i64,
+        Part::Two => line
+            .iter()
+            .fold(0i64, |acc, &c| {
+                if c.is_ascii_alphabetic() || c == b'-' {
+                    acc + 1
+                } else {
+                    assert!(c.is_ascii_digit());
+                    acc - 1
+                }
+            })
+            .abs(),
+        Part::Three => {
+            let (total, running) = line.iter().fold((0i64, 0i64), |(total, running), &c| {
+                if c == b'-' {
+                    (total + running.abs() + 1, 0)
+                } else if c.is_ascii_alphabetic() {
+                    (total, running + 1)
+                } else {
+                    assert!(c.is_ascii_digit());
+                    (total, running - 1)
+                }
+            });
+            total + running.abs()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+tv8cmj0i2951190z5w44fe205k542l5818ds05ib425h9lj260ud38-l6a06
+a586m0eeuqqvt5-k-8434hb27ytha3i75-lw23-0cj856l7zn8234a05eron
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 52);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 18);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 26);
+    }
+
+    #[test]
+    fn streamed_answers() {
+        let answers = Problem8::stream(&mut EXAMPLE.as_bytes(), &(), &Part::ALL).unwrap();
+        assert_eq!(answers, [Answer::I64(52), Answer::I64(18), Answer::I64(26)]);
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
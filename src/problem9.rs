@@ -0,0 +1,294 @@
+use crate::{
+    Answer, ParseError, Solution,
+    generate::{LOWERCASE, Rng, UPPERCASE},
+    parse::Source,
+    trace,
+};
+use std::{
+    collections::{HashMap, VecDeque, hash_map::Entry},
+    fmt::Display,
+    iter::once,
+};
+
+pub struct Problem9;
+
+impl Solution for Problem9 {
+    const PROBLEM: u8 = 9;
+
+    type Data<'a> = Data<'a>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Data<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn print(data: &Self::Data<'_>) -> String {
+        print_input(data)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn part1(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part1(data).into()
+    }
+
+    fn part2(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part2(data).into()
+    }
+
+    fn part3(data: &Self::Data<'_>, _: &Self::Params) -> Answer {
+        solve_part3(data).into()
+    }
+}
+
+type Money = i64;
+type Account<'a> = &'a str;
+
+#[derive(PartialEq, Debug)]
+struct Transaction<'a> {
+    from: Account<'a>,
+    to: Account<'a>,
+    amount: Money,
+}
+
+impl Display for Transaction<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FROM {} TO {} AMT {}", self.from, self.to, self.amount)
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Data<'a> {
+    initial_balances: HashMap<Account<'a>, Money>,
+    transactions: Vec<Transaction<'a>>,
+}
+
+struct Debt<'a> {
+    to: Account<'a>,
+    amount: Money,
+}
+
+fn parse_input(input: &str) -> Result<Data<'_>, ParseError> {
+    let source = Source::new(Problem9::PROBLEM, input);
+    let [balances, transactions] =
+        source.sections(["a section of balances", "a section of transactions"])?;
+
+    let mut initial_balances = HashMap::new();
+    for line in source.lines(balances) {
+        let record = source.record(line, &["HAS"])?;
+        initial_balances.insert(
+            record.head("an account")?,
+            record.parse("HAS", "an amount")?,
+        );
+    }
+
+    let account = |token| {
+        if initial_balances.contains_key(token) {
+            Ok(token)
+        } else {
+            Err(source.error(token, "an account with an initial balance"))
+        }
+    };
+
+    let transactions = source
+        .lines(transactions)
+        .map(|line| {
+            let record = source.record(line, &["FROM", "TO", "AMT"])?;
+            record.headless()?;
+            Ok(Transaction {
+                from: account(record.field("FROM", "an account")?)?,
+                to: account(record.field("TO", "an account")?)?,
+                amount: record.parse("AMT", "an amount")?,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Data {
+        initial_balances,
+        transactions,
+    })
+}
+
+/// Writes the initial balances, which are not ordered once parsed, by account name.
+fn print_input(data: &Data) -> String {
+    let mut balances: Vec<_> = data.initial_balances.iter().collect();
+    balances.sort();
+    let mut input: String = balances
+        .into_iter()
+        .map(|(account, balance)| format!("{account} HAS {balance}\n"))
+        .collect();
+    input.push('\n');
+    input.extend(
+        data.transactions
+            .iter()
+            .map(|transaction| format!("{transaction}\n")),
+    );
+    input
+}
+
+/// Writes the balances of about `size` / 4 accounts, at least three, and `size` transactions.
+fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut accounts: Vec<String> = vec![];
+    while accounts.len() < (size / 4).max(3) {
+        let len = rng.index(6) + 2;
+        let name = rng.word(1, UPPERCASE) + &rng.word(len, LOWERCASE);
+        if !accounts.contains(&name) {
+            accounts.push(name);
+        }
+    }
+    let mut input = String::new();
+    for account in &accounts {
+        input += &format!("{account} HAS {}\n", rng.int(0..=1_000));
+    }
+    input.push('\n');
+    for _ in 0..size.max(1) {
+        let from = rng.index(accounts.len());
+        let to = (from + 1 + rng.index(accounts.len() - 1)) % accounts.len();
+        input += &format!(
+            "FROM {} TO {} AMT {}\n",
+            accounts[from],
+            accounts[to],
+            rng.int(1..=500)
+        );
+    }
+    input
+}
+
+/// Traces a transfer of `amount` between two accounts, and what they hold after it.
+fn trace_transfer(
+    kind: &'static str,
+    from: Account,
+    to: Account,
+    amount: Money,
+    balances: &HashMap<Account, Money>,
+) {
+    trace::emit(kind, || {
+        vec![
+            ("from", from.to_owned()),
+            ("to", to.to_owned()),
+            ("amount", amount.to_string()),
+            ("from_balance", balances[from].to_string()),
+            ("to_balance", balances[to].to_string()),
+        ]
+    });
+}
+
+fn solve_part1(data: &Data) -> i64 {
+    let mut balances = data.initial_balances.clone();
+    for Transaction { from, to, amount } in data.transactions.iter() {
+        *balances.get_mut(from).unwrap() -= amount;
+        *balances.get_mut(to).unwrap() += amount;
+        trace_transfer("transaction", from, to, *amount, &balances);
+    }
+    compute_result(balances)
+}
+
+fn solve_part2(data: &Data) -> i64 {
+    let mut balances = data.initial_balances.clone();
+    for Transaction { from, to, amount } in data.transactions.iter() {
+        let amount = *amount.min(balances.get(from).unwrap());
+        *balances.get_mut(from).unwrap() -= amount;
+        *balances.get_mut(to).unwrap() += amount;
+        trace_transfer("transaction", from, to, amount, &balances);
+    }
+    compute_result(balances)
+}
+
+fn solve_part3(data: &Data) -> i64 {
+    let mut balances = data.initial_balances.clone();
+    let mut debts = HashMap::<Account, VecDeque<Debt>>::new();
+    for Transaction { from, to, amount } in data.transactions.iter() {
+        let cash_amount = *amount.min(balances.get(from).unwrap());
+        if cash_amount < *amount {
+            let new_debt = Debt {
+                to,
+                amount: *amount - cash_amount,
+            };
+            match debts.entry(from) {
+                Entry::Occupied(mut occupied_entry) => {
+                    occupied_entry.get_mut().push_back(new_debt);
+                }
+                Entry::Vacant(vacant_entry) => {
+                    vacant_entry.insert(VecDeque::from_iter(once(new_debt)));
+                }
+            };
+        }
+        *balances.get_mut(from).unwrap() -= cash_amount;
+        *balances.get_mut(to).unwrap() += cash_amount;
+        trace_transfer("transaction", from, to, cash_amount, &balances);
+        let mut debtors = vec![*to];
+        while let Some(debtor) = debtors.pop() {
+            let maybe_debts = debts.get_mut(debtor);
+            if let Some(debts) = maybe_debts {
+                while !debts.is_empty() && *balances.get(debtor).unwrap() > 0 {
+                    let debt = debts.front_mut().unwrap();
+                    let repaid_amount = debt.amount.min(*balances.get(debtor).unwrap());
+                    *balances.get_mut(debtor).unwrap() -= repaid_amount;
+                    *balances.get_mut(debt.to).unwrap() += repaid_amount;
+                    trace_transfer("repayment", debtor, debt.to, repaid_amount, &balances);
+                    debtors.push(debt.to);
+                    debt.amount -= repaid_amount;
+                    if debt.amount == 0 {
+                        debts.pop_front();
+                    }
+                }
+            }
+        }
+    }
+    compute_result(balances)
+}
+
+fn compute_result(balances: HashMap<Account, Money>) -> Money {
+    let mut balances: Vec<_> = balances.into_values().collect();
+    balances.sort_unstable();
+    balances.into_iter().rev().take(3).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Alpha HAS 131
+Bravo HAS 804
+Charlie HAS 348
+Delta HAS 187
+Echo HAS 649
+Foxtrot HAS 739
+
+FROM Echo TO Foxtrot AMT 328
+FROM Charlie TO Bravo AMT 150
+FROM Charlie TO Delta AMT 255
+FROM Alpha TO Delta AMT 431
+FROM Foxtrot TO Alpha AMT 230
+FROM Echo TO Foxtrot AMT 359
+FROM Echo TO Alpha AMT 269
+FROM Delta TO Foxtrot AMT 430
+FROM Bravo TO Echo AMT 455
+FROM Charlie TO Delta AMT 302
+";
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve_part1(&parse_input(EXAMPLE).unwrap()), 2870);
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve_part2(&parse_input(EXAMPLE).unwrap()), 2542);
+    }
+
+    #[test]
+    fn part3() {
+        assert_eq!(solve_part3(&parse_input(EXAMPLE).unwrap()), 2511);
+    }
+
+    #[test]
+    fn printed_input_parses_back() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(parse_input(&print_input(&data)).unwrap(), data);
+    }
+}
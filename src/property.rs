@@ -0,0 +1,99 @@
+//! A small property-testing harness: a property is checked on many random values, and the first
+//! counterexample is shrunk to a minimal one before being reported.
+
+use crate::generate::Rng;
+use std::{
+    fmt::Debug,
+    panic::{AssertUnwindSafe, catch_unwind},
+};
+
+const CASES: u64 = 300;
+
+/// Checks `property` on values from `generate`, seeded from 0 upwards.
+///
+/// A value fails when the property returns `false` or panics. The first failing value is replaced
+/// by the first of its `shrink` candidates that still fails, until none does, and the result is
+/// reported in the panic message.
+pub fn check<T: Debug>(
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> bool,
+) {
+    let fails = |value: &T| !catch_unwind(AssertUnwindSafe(|| property(value))).unwrap_or(false);
+    for seed in 0..CASES {
+        let mut value = generate(&mut Rng::new(seed));
+        if fails(&value) {
+            while let Some(smaller) = shrink(&value).into_iter().find(|value| fails(value)) {
+                value = smaller;
+            }
+            panic!("property failed for seed {seed}, minimal counterexample: {value:?}");
+        }
+    }
+}
+
+/// Shorter versions of `values`, without a chunk of them, then versions with one value shrunk.
+pub fn shrink_vec<T: Clone>(values: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = vec![];
+    let mut chunk = values.len();
+    while chunk > 0 {
+        for start in (0..=values.len() - chunk).step_by(chunk) {
+            candidates.push([&values[..start], &values[start + chunk..]].concat());
+        }
+        chunk /= 2;
+    }
+    for (i, value) in values.iter().enumerate() {
+        for smaller in shrink(value) {
+            let mut candidate = values.to_vec();
+            candidate[i] = smaller;
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+/// Integers closer to `target`: the target itself, halfway to it, then one step towards it.
+pub fn shrink_int(value: i64, target: i64) -> Vec<i64> {
+    let mut candidates = vec![];
+    for candidate in [
+        target,
+        target + (value - target) / 2,
+        value - (value - target).signum(),
+    ] {
+        if candidate != value && !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinking() {
+        assert_eq!(shrink_int(10, 0), [0, 5, 9]);
+        assert_eq!(shrink_int(-3, 0), [0, -1, -2]);
+        assert!(shrink_int(4, 4).is_empty());
+        assert_eq!(
+            shrink_vec(&[1, 2], |&x| shrink_int(x, 0)),
+            [vec![], vec![2], vec![1], vec![0, 2], vec![1, 0], vec![1, 1]]
+        );
+    }
+
+    #[test]
+    fn counterexamples_are_minimal() {
+        let result = catch_unwind(|| {
+            check(
+                |rng| (0..10).map(|_| rng.int(0..=100)).collect::<Vec<_>>(),
+                |values| shrink_vec(values, |&x| shrink_int(x, 0)),
+                |values| values.iter().all(|&x| x < 50),
+            )
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(
+            message.ends_with("minimal counterexample: [50]"),
+            "{message}"
+        );
+    }
+}
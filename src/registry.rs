@@ -0,0 +1,344 @@
+use crate::{
+    Answer, ParseError, Part, Solution,
+    bench::{self, Timings},
+    generate::Rng,
+    params::{self, Override, ParamSet},
+    problem1::Problem1,
+    problem2::Problem2,
+    problem3::Problem3,
+    problem4::Problem4,
+    problem5::Problem5,
+    problem6::Problem6,
+    problem7::Problem7,
+    problem8::Problem8,
+    problem9::Problem9,
+    problem10::Problem10,
+    problem11::Problem11,
+    problem12::Problem12,
+    problem13::Problem13,
+    problem14::Problem14,
+    problem15::Problem15,
+    problem16::Problem16,
+    problem17::Problem17,
+    problem18::Problem18,
+    stream::{StreamError, Streaming},
+    trace,
+};
+use std::{
+    any::Any,
+    io::BufRead,
+    time::{Duration, Instant},
+};
+
+/// The answer to one part, with the time it took to solve it from the parsed input.
+#[derive(Clone, Debug)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// Whether the answer comes from the [`cache`](crate::cache), without being solved again.
+    pub cached: bool,
+}
+
+/// The parameters of one problem, with their concrete type erased.
+pub struct ProblemParams(Box<dyn ParamSet>);
+
+impl ProblemParams {
+    /// The name and value of each parameter.
+    pub fn describe(&self) -> Vec<(&'static str, String)> {
+        self.0.describe()
+    }
+
+    fn get<P: ParamSet>(&self) -> &P {
+        (self.0.as_ref() as &dyn Any)
+            .downcast_ref()
+            .expect("the parameters should belong to the problem being solved")
+    }
+}
+
+/// A type-erased handle on one problem, so that problems can be selected at runtime.
+pub struct Problem {
+    pub id: u8,
+    /// The challenge set of the problem, see [`Solution::SET`].
+    pub set: Option<&'static str>,
+    /// The version of the solver, see [`Solution::VERSION`].
+    pub version: &'static str,
+    params: fn(&[Override]) -> Result<ProblemParams, String>,
+    solve: SolveFn,
+    bench: BenchFn,
+    generate: fn(&mut Rng, usize) -> String,
+    load: fn(&'static str) -> Result<Loaded, ParseError>,
+    normalize: fn(&str) -> Result<String, ParseError>,
+    stream: Option<StreamFn>,
+}
+
+type SolveFn = fn(&str, &ProblemParams, &[Part]) -> Result<Vec<Solved>, ParseError>;
+
+type BenchFn = fn(&str, &ProblemParams, &[Part], usize) -> Result<Timings, ParseError>;
+
+type StreamFn = fn(&mut dyn BufRead, &ProblemParams, &[Part]) -> Result<Vec<Solved>, StreamError>;
+
+impl Problem {
+    pub(crate) const fn new<S: Solution>() -> Self {
+        Self {
+            id: S::PROBLEM,
+            set: S::SET,
+            version: S::VERSION,
+            params: params::<S>,
+            solve: solve::<S>,
+            bench: bench::<S>,
+            generate: S::generate,
+            load: load::<S>,
+            normalize: normalize::<S>,
+            stream: None,
+        }
+    }
+
+    pub(crate) const fn streaming<S: Streaming>() -> Self {
+        Self {
+            stream: Some(stream::<S>),
+            ..Self::new::<S>()
+        }
+    }
+
+    /// The parameters of this problem: the defaults, with the `(name, value)` overrides applied.
+    pub fn params(&self, overrides: &[Override]) -> Result<ProblemParams, String> {
+        (self.params)(overrides)
+    }
+
+    pub fn default_params(&self) -> ProblemParams {
+        self.params(&[])
+            .expect("the default parameters should be valid")
+    }
+
+    /// Parses `input` once and solves each of the requested `parts`, in order.
+    ///
+    /// `params` must come from [`Problem::params`] on the same problem.
+    pub fn solve(
+        &self,
+        input: &str,
+        params: &ProblemParams,
+        parts: &[Part],
+    ) -> Result<Vec<Solved>, ParseError> {
+        (self.solve)(input, params, parts)
+    }
+
+    /// Times the parsing of `input` and each of the requested `parts` over `runs` runs.
+    pub fn bench(
+        &self,
+        input: &str,
+        params: &ProblemParams,
+        parts: &[Part],
+        runs: usize,
+    ) -> Result<Timings, ParseError> {
+        (self.bench)(input, params, parts, runs)
+    }
+
+    /// A random input of about `size` records, always the same for a given `seed`.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+
+    /// Parses `input` once, to solve parts and show views from it any number of times.
+    ///
+    /// The parsed data may borrow from `input`, which must therefore live as long as the program.
+    pub fn load(&self, input: &'static str) -> Result<Loaded, ParseError> {
+        (self.load)(input)
+    }
+
+    /// Parses `input` and writes it back in the canonical format of the problem.
+    pub fn normalize(&self, input: &str) -> Result<String, ParseError> {
+        (self.normalize)(input)
+    }
+
+    /// Whether this problem can be solved from a reader, see [`Problem::stream`].
+    pub fn can_stream(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Solves each of the requested `parts` in a single pass over `reader`, without keeping the
+    /// input. The parts are solved together, so each is timed as the whole pass.
+    ///
+    /// Panics if the problem [cannot stream](Problem::can_stream).
+    pub fn stream(
+        &self,
+        reader: &mut dyn BufRead,
+        params: &ProblemParams,
+        parts: &[Part],
+    ) -> Result<Vec<Solved>, StreamError> {
+        let stream = self.stream.expect("the problem should be able to stream");
+        stream(reader, params, parts)
+    }
+}
+
+/// The parsed input of a problem, with its concrete type erased.
+pub struct Loaded(Box<dyn LoadedData>);
+
+impl Loaded {
+    /// Solves `part`; `params` must come from [`Problem::params`] on the same problem.
+    pub fn solve(&self, params: &ProblemParams, part: Part) -> Solved {
+        self.0.solve(params, part)
+    }
+
+    /// The name and description of each view of the problem.
+    pub fn views(&self) -> Vec<(&'static str, &'static str)> {
+        self.0.views()
+    }
+
+    /// Shows the view `name`, if the problem has one by this name.
+    pub fn show(&self, name: &str, params: &ProblemParams) -> Option<String> {
+        self.0.show(name, params)
+    }
+}
+
+trait LoadedData {
+    fn solve(&self, params: &ProblemParams, part: Part) -> Solved;
+
+    fn views(&self) -> Vec<(&'static str, &'static str)>;
+
+    fn show(&self, name: &str, params: &ProblemParams) -> Option<String>;
+}
+
+struct Parsed<S: Solution>(S::Data<'static>);
+
+impl<S: Solution> LoadedData for Parsed<S> {
+    fn solve(&self, params: &ProblemParams, part: Part) -> Solved {
+        let start = Instant::now();
+        let answer = S::solve(&self.0, params.get(), part);
+        Solved {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+            cached: false,
+        }
+    }
+
+    fn views(&self) -> Vec<(&'static str, &'static str)> {
+        S::VIEWS
+            .iter()
+            .map(|view| (view.name, view.about))
+            .collect()
+    }
+
+    fn show(&self, name: &str, params: &ProblemParams) -> Option<String> {
+        let view = S::VIEWS.iter().find(|view| view.name == name)?;
+        Some((view.show)(&self.0, params.get()))
+    }
+}
+
+fn params<S: Solution>(overrides: &[Override]) -> Result<ProblemParams, String> {
+    let params: S::Params = params::with_overrides(overrides)?;
+    Ok(ProblemParams(Box::new(params)))
+}
+
+fn load<S: Solution>(input: &'static str) -> Result<Loaded, ParseError> {
+    Ok(Loaded(Box::new(Parsed::<S>(S::parse(input)?))))
+}
+
+fn normalize<S: Solution>(input: &str) -> Result<String, ParseError> {
+    Ok(S::print(&S::parse(input)?))
+}
+
+fn solve<S: Solution>(
+    input: &str,
+    params: &ProblemParams,
+    parts: &[Part],
+) -> Result<Vec<Solved>, ParseError> {
+    let params = params.get();
+    let data = S::parse(input)?;
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            trace::emit("part", || vec![("part", part.to_string())]);
+            let start = Instant::now();
+            let answer = S::solve(&data, params, part);
+            Solved {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+                cached: false,
+            }
+        })
+        .collect())
+}
+
+fn stream<S: Streaming>(
+    reader: &mut dyn BufRead,
+    params: &ProblemParams,
+    parts: &[Part],
+) -> Result<Vec<Solved>, StreamError> {
+    let start = Instant::now();
+    let answers = S::stream(reader, params.get(), parts)?;
+    let elapsed = start.elapsed();
+    Ok(parts
+        .iter()
+        .zip(answers)
+        .map(|(&part, answer)| Solved {
+            part,
+            answer,
+            elapsed,
+            cached: false,
+        })
+        .collect())
+}
+
+fn bench<S: Solution>(
+    input: &str,
+    params: &ProblemParams,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Timings, ParseError> {
+    bench::measure::<S>(input, params.get(), parts, runs)
+}
+
+pub static PROBLEMS: [Problem; 18] = [
+    Problem::new::<Problem1>(),
+    Problem::new::<Problem2>(),
+    Problem::new::<Problem3>(),
+    Problem::streaming::<Problem4>(),
+    Problem::new::<Problem5>(),
+    Problem::streaming::<Problem6>(),
+    Problem::new::<Problem7>(),
+    Problem::streaming::<Problem8>(),
+    Problem::new::<Problem9>(),
+    Problem::new::<Problem10>(),
+    Problem::new::<Problem11>(),
+    Problem::new::<Problem12>(),
+    Problem::new::<Problem13>(),
+    Problem::new::<Problem14>(),
+    Problem::new::<Problem15>(),
+    Problem::new::<Problem16>(),
+    Problem::new::<Problem17>(),
+    Problem::new::<Problem18>(),
+];
+
+pub fn problem(id: u8) -> Option<&'static Problem> {
+    PROBLEMS.iter().find(|problem| problem.id == id)
+}
+
+/// The problems of one challenge set.
+#[derive(Clone, Copy)]
+pub struct Set {
+    /// The name of the set, or `None` for this crate's own.
+    pub name: Option<&'static str>,
+    pub problems: &'static [Problem],
+}
+
+impl Set {
+    const fn new(name: Option<&'static str>, problems: &'static [Problem]) -> Self {
+        Self { name, problems }
+    }
+
+    pub fn problem(&self, id: u8) -> Option<&'static Problem> {
+        self.problems.iter().find(|problem| problem.id == id)
+    }
+}
+
+/// The challenge set called `name`, or this crate's own for `None`. The other sets are added by
+/// `codyssi new --set`, each in a `set_<name>` module with its own `PROBLEMS`.
+pub fn set(name: Option<&str>) -> Option<Set> {
+    match name {
+        None => Some(Set::new(None, &PROBLEMS)),
+        _ => None,
+    }
+}
@@ -0,0 +1,394 @@
+//! Access to the Codyssi website, through an [`Http`] client, so that tests can stand a local
+//! server in for the website.
+
+use crate::{Part, input};
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{Read, Write},
+    net::TcpStream,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+/// Environment variable holding the session token of the website.
+pub const SESSION_VAR: &str = "CODYSSI_SESSION";
+
+/// Environment variable overriding the address of the website, e.g. with a local server.
+pub const URL_VAR: &str = "CODYSSI_URL";
+
+const DEFAULT_URL: &str = "https://www.codyssi.com";
+
+const FORM_TYPE: &str = "application/x-www-form-urlencoded";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests to a URL, with extra headers.
+pub trait Http {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, RemoteError>;
+
+    /// Posts `form`, which is URL-encoded.
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &str,
+    ) -> Result<Response, RemoteError>;
+}
+
+#[derive(Debug)]
+pub enum RemoteError {
+    NoSession,
+    Transport { url: String, message: String },
+    Status { url: String, status: u16 },
+}
+
+impl Display for RemoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemoteError::NoSession => write!(
+                f,
+                "no session token: set {SESSION_VAR}, or write it to {}",
+                session_path().display()
+            ),
+            RemoteError::Transport { url, message } => write!(f, "cannot reach {url}: {message}"),
+            RemoteError::Status { url, status } => {
+                write!(f, "{url} answered with status {status}")
+            }
+        }
+    }
+}
+
+impl Error for RemoteError {}
+
+/// HTTP/1.0 over a plain TCP connection, which is enough for `http` URLs, such as a local server.
+pub struct PlainHttp;
+
+impl Http for PlainHttp {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, RemoteError> {
+        self.send(url, headers, None)
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &str,
+    ) -> Result<Response, RemoteError> {
+        self.send(url, headers, Some(form))
+    }
+}
+
+impl PlainHttp {
+    fn send(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: Option<&str>,
+    ) -> Result<Response, RemoteError> {
+        let transport = |message: String| RemoteError::Transport {
+            url: url.to_owned(),
+            message,
+        };
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| transport("only `http` URLs are supported".to_owned()))?;
+        let (host, path) = match rest.split_once('/') {
+            Some((host, path)) => (host, format!("/{path}")),
+            None => (rest, "/".to_owned()),
+        };
+        let method = if form.is_some() { "POST" } else { "GET" };
+        let mut request = format!("{method} {path} HTTP/1.0\r\nHost: {host}\r\n");
+        for (name, value) in headers {
+            request += &format!("{name}: {value}\r\n");
+        }
+        if let Some(form) = form {
+            request += &format!("Content-Type: {FORM_TYPE}\r\n");
+            request += &format!("Content-Length: {}\r\n", form.len());
+        }
+        request += "\r\n";
+        request += form.unwrap_or_default();
+
+        let address = if host.contains(':') {
+            host.to_owned()
+        } else {
+            format!("{host}:80")
+        };
+        let mut stream = TcpStream::connect(address).map_err(|err| transport(err.to_string()))?;
+        stream
+            .write_all(request.as_bytes())
+            .map_err(|err| transport(err.to_string()))?;
+        let mut response = String::new();
+        stream
+            .read_to_string(&mut response)
+            .map_err(|err| transport(err.to_string()))?;
+        parse_response(&response).ok_or_else(|| transport("malformed response".to_owned()))
+    }
+}
+
+fn parse_response(response: &str) -> Option<Response> {
+    let (head, body) = response.split_once("\r\n\r\n")?;
+    let status = head.lines().next()?.split_ascii_whitespace().nth(1)?;
+    Some(Response {
+        status: status.parse().ok()?,
+        body: body.to_owned(),
+    })
+}
+
+/// Runs `curl`, for the `https` URLs of the website. The request is passed on its standard input,
+/// so that the session token does not show in the list of processes.
+pub struct Curl;
+
+impl Http for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response, RemoteError> {
+        self.send(url, headers, None)
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &str,
+    ) -> Result<Response, RemoteError> {
+        self.send(url, headers, Some(form))
+    }
+}
+
+impl Curl {
+    fn send(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: Option<&str>,
+    ) -> Result<Response, RemoteError> {
+        let transport = |message: String| RemoteError::Transport {
+            url: url.to_owned(),
+            message,
+        };
+        let mut config = format!("url = {}\n", curl_string(url));
+        for (name, value) in headers {
+            config += &format!("header = {}\n", curl_string(&format!("{name}: {value}")));
+        }
+        if let Some(form) = form {
+            config += &format!("data-binary = {}\n", curl_string(form));
+        }
+
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--config", "-"])
+            .args(["--write-out", "\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| transport(format!("cannot run curl: {err}")))?;
+        child
+            .stdin
+            .take()
+            .expect("the standard input of curl should be piped")
+            .write_all(config.as_bytes())
+            .map_err(|err| transport(format!("cannot run curl: {err}")))?;
+        let output = child
+            .wait_with_output()
+            .map_err(|err| transport(format!("cannot run curl: {err}")))?;
+        if !output.status.success() {
+            return Err(transport(
+                String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            ));
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = output
+            .rsplit_once('\n')
+            .ok_or_else(|| transport("malformed response".to_owned()))?;
+        Ok(Response {
+            status: status
+                .parse()
+                .map_err(|_| transport("malformed response".to_owned()))?,
+            body: body.to_owned(),
+        })
+    }
+}
+
+/// Quotes `text` for a curl configuration file.
+fn curl_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Where the session token is read from, when [`SESSION_VAR`] is not set.
+pub fn session_path() -> PathBuf {
+    input::data_dir().join("session")
+}
+
+/// The website, as seen by the user of a session.
+pub struct Site {
+    base_url: String,
+    session: String,
+    http: Box<dyn Http>,
+}
+
+impl Site {
+    pub fn new(base_url: &str, session: &str, http: Box<dyn Http>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            http,
+        }
+    }
+
+    /// The website at [`URL_VAR`] (default: codyssi.com), with the session token of
+    /// [`SESSION_VAR`] or of the [`session_path`] file. `http` URLs are reached directly, and
+    /// `https` ones with curl.
+    pub fn from_env() -> Result<Self, RemoteError> {
+        let base_url = env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_owned());
+        let session = env::var(SESSION_VAR)
+            .or_else(|_| fs::read_to_string(session_path()))
+            .map_err(|_| RemoteError::NoSession)?;
+        let session = session.trim();
+        if session.is_empty() {
+            return Err(RemoteError::NoSession);
+        }
+        let http: Box<dyn Http> = if base_url.starts_with("http://") {
+            Box::new(PlainHttp)
+        } else {
+            Box::new(Curl)
+        };
+        Ok(Self::new(&base_url, session, http))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads the input of `problem`.
+    pub fn fetch_input(&self, problem: u8) -> Result<String, RemoteError> {
+        let url = format!("{}/view_problem_{problem}_input", self.base_url);
+        let response = self.http.get(&url, &[("Cookie", &self.cookie())])?;
+        if response.status != 200 {
+            return Err(RemoteError::Status {
+                url,
+                status: response.status,
+            });
+        }
+        Ok(response.body)
+    }
+
+    /// Submits `answer` to `part` of `problem`, and returns the reply of the website.
+    pub fn submit_answer(
+        &self,
+        problem: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, RemoteError> {
+        let url = format!("{}/submit_problem_{problem}_part_{part}", self.base_url);
+        let form = format!("answer={}", form_encode(answer));
+        let response = self.http.post(&url, &[("Cookie", &self.cookie())], &form)?;
+        if response.status != 200 {
+            return Err(RemoteError::Status {
+                url,
+                status: response.status,
+            });
+        }
+        Ok(response.body)
+    }
+}
+
+/// Encodes `text` as a form value: letters, digits and `-._~` are kept, the rest is escaped.
+fn form_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves `responses` to as many requests on a local port, and returns the URL of the server,
+    /// and the requests it received once it is done.
+    fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut requests = vec![];
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&mut stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.strip_prefix("Content-Length: ") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request += &line;
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                request += &String::from_utf8(body).unwrap();
+                requests.push(request);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, server)
+    }
+
+    fn ok(body: &str) -> String {
+        format!("HTTP/1.0 200 OK\r\nContent-Type: text/plain\r\n\r\n{body}")
+    }
+
+    #[test]
+    fn inputs_are_fetched_with_the_session() {
+        let (url, server) = serve(vec![
+            ok("1\n2\n+\n"),
+            "HTTP/1.0 404 Not Found\r\n\r\n".to_owned(),
+        ]);
+        let site = Site::new(&format!("{url}/"), "secret", Box::new(PlainHttp));
+        assert_eq!(site.fetch_input(1).unwrap(), "1\n2\n+\n");
+        let err = site.fetch_input(19).unwrap_err();
+        assert!(matches!(err, RemoteError::Status { status: 404, .. }));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /view_problem_1_input HTTP/1.0\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=secret\r\n"));
+    }
+
+    #[test]
+    fn answers_are_posted_as_forms() {
+        let (url, server) = serve(vec![ok("That is correct!")]);
+        let site = Site::new(&url, "secret", Box::new(PlainHttp));
+        let reply = site.submit_answer(17, Part::Three, "S1_0-S1_2").unwrap();
+        assert_eq!(reply, "That is correct!");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /submit_problem_17_part_3 HTTP/1.0\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=secret\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nanswer=S1_0-S1_2"));
+        assert_eq!(form_encode("4iWAbo%6 x"), "4iWAbo%256%20x");
+    }
+
+    #[test]
+    fn curl_configuration() {
+        assert_eq!(curl_string(r#"a "b" \c"#), r#""a \"b\" \\c""#);
+    }
+}
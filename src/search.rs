@@ -0,0 +1,308 @@
+//! Generic graph searches over states given by a successor function: breadth-first, with or
+//! without labels, Dijkstra and A*. Each search records the predecessor of the states it reaches, so that paths can be rebuilt.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque, hash_map::Entry},
+    hash::Hash,
+    ops::Add,
+};
+
+/// What a search found, from its start state.
+pub struct Search<S, C> {
+    /// The cost of the cheapest path found to each reached state. It is final for the states
+    /// the search explored, which are all of them unless it stopped at a goal.
+    pub costs: HashMap<S, C>,
+    /// The state each reached state was reached from, along the cheapest path found.
+    predecessors: HashMap<S, S>,
+    /// The first state satisfying the goal, when one was reached.
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            costs: HashMap::from([(start, cost)]),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The cost of the path to the goal, when one was reached.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().map(|goal| self.costs[goal])
+    }
+
+    pub fn predecessor(&self, state: &S) -> Option<&S> {
+        self.predecessors.get(state)
+    }
+
+    /// The states from the start to `state`, both included, or nothing when it was not reached.
+    pub fn path_to(&self, state: &S) -> Vec<S> {
+        if !self.costs.contains_key(state) {
+            return vec![];
+        }
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessor(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Explores the states in order of their number of steps from `start`, until one satisfies
+/// `is_goal`, or every reachable state has been explored. The cost of a state is its number of
+/// steps.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let cost = search.costs[&state] + 1;
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = search.costs.entry(next.clone()) {
+                entry.insert(cost);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Like [`bfs`], over states made of a key and a label, such as a position and the damage taken
+/// to reach it: a state is skipped when one with the same key and a label no higher was reached
+/// before, or with a lower label by the time it is explored. This requires the lower labels to
+/// lead to goals in as many steps or fewer.
+pub fn labelled_bfs<K, L, I>(
+    start: (K, L),
+    mut successors: impl FnMut(&(K, L)) -> I,
+    mut is_goal: impl FnMut(&(K, L)) -> bool,
+) -> Search<(K, L), usize>
+where
+    K: Clone + Eq + Hash,
+    L: Copy + Ord + Hash,
+    I: IntoIterator<Item = (K, L)>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut lowest = HashMap::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        if lowest[&state.0] < state.1 {
+            continue;
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let cost = search.costs[&state] + 1;
+        for next in successors(&state) {
+            match lowest.entry(next.0.clone()) {
+                Entry::Occupied(entry) if *entry.get() <= next.1 => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert(next.1);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(next.1);
+                }
+            }
+            search.costs.insert(next.clone(), cost);
+            search.predecessors.insert(next.clone(), state.clone());
+            queue.push_back(next);
+        }
+    }
+    search
+}
+
+/// Explores the states in order of their cost from `start`, where `successors` gives each next
+/// state with the non-negative cost of the step to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores first the states whose cost plus `heuristic` is lowest.
+///
+/// The heuristic must never overestimate the cost left to a goal, and must not decrease by more
+/// than the cost of a step, for the cost of the goal to be the lowest one.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut explored = HashSet::new();
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        state: start,
+    }]);
+    while let Some(Queued { state, .. }) = queue.pop() {
+        if !explored.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let cost = search.costs[&state];
+        for (next, step) in successors(&state) {
+            debug_assert!(
+                step >= C::default(),
+                "the cost of a step should not be negative"
+            );
+            let next_cost = cost + step;
+            if explored.contains(&next)
+                || search
+                    .costs
+                    .get(&next)
+                    .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+            search.costs.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), state.clone());
+            queue.push(Queued {
+                priority: next_cost + heuristic(&next),
+                state: next,
+            });
+        }
+    }
+    search
+}
+
+/// A state waiting to be explored, which the max-heap pops lowest priority first.
+struct Queued<S, C> {
+    priority: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of states 0 to 9, with a costly shortcut from 0 to 9.
+    fn line(state: &u32) -> Vec<(u32, u32)> {
+        let mut next = vec![];
+        if *state < 9 {
+            next.push((state + 1, 1));
+        }
+        if *state == 0 {
+            next.push((9, 20));
+        }
+        next
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let search = bfs(
+            0,
+            |state| line(state).into_iter().map(|(next, _)| next),
+            |_| false,
+        );
+        assert_eq!(search.costs.len(), 10);
+        assert_eq!(search.costs[&9], 1);
+        assert_eq!(search.costs[&8], 8);
+        assert_eq!(search.path_to(&9), [0, 9]);
+
+        let search = bfs(
+            0,
+            |state| line(state).into_iter().map(|(next, _)| next),
+            |&s| s == 3,
+        );
+        assert_eq!((search.goal, search.goal_cost()), (Some(3), Some(3)));
+        assert!(search.path_to(&7).is_empty());
+    }
+
+    #[test]
+    fn labelled_bfs_skips_higher_labels() {
+        // The shortcut to 9 adds its cost to the label, which must stay under 10.
+        let search = labelled_bfs(
+            (0, 0),
+            |&(state, label)| {
+                line(&state)
+                    .into_iter()
+                    .map(move |(next, cost)| (next, label + cost))
+                    .filter(|&(_, label)| label < 10)
+            },
+            |&(state, _)| state == 9,
+        );
+        assert_eq!(search.goal, Some((9, 9)));
+        assert_eq!(search.goal_cost(), Some(9));
+        assert_eq!(search.path_to(&(9, 9)).len(), 10);
+
+        // Without the limit, the shortcut is reached first, and the cheaper arrival at 9 later
+        // is still explored since its label is lower.
+        let search = labelled_bfs(
+            (0, 0),
+            |&(state, label)| {
+                line(&state)
+                    .into_iter()
+                    .map(move |(next, cost)| (next, label + cost))
+            },
+            |_| false,
+        );
+        assert_eq!(search.costs[&(9, 20)], 1);
+        assert_eq!(search.costs[&(9, 9)], 9);
+    }
+
+    #[test]
+    fn dijkstra_finds_cheapest_paths() {
+        let search = dijkstra(0, line, |_| false);
+        assert_eq!(search.costs[&9], 9);
+        assert_eq!(search.path_to(&9), (0..=9).collect::<Vec<_>>());
+        assert_eq!(search.predecessor(&0), None);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let search = astar(0, line, |&state| 9 - state, |&state| state == 9);
+        assert_eq!(search.goal_cost(), Some(9));
+        assert_eq!(search.path_to(&9).len(), 10);
+    }
+}
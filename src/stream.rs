@@ -0,0 +1,147 @@
+//! Solving from a reader in a single pass, for the problems whose input is a long run of
+//! independent lines or characters, so that inputs larger than the memory can be solved.
+
+use crate::{Answer, ParseError, Part, Solution, parse::Source};
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead},
+};
+
+/// A problem that can also be solved from a reader, in constant memory, or in the memory of its
+/// longest line for the problems read line by line.
+pub trait Streaming: Solution {
+    /// Solves each of `parts` from the input read from `reader`, in a single pass, with the same
+    /// answers as from [`Solution::parse`].
+    fn stream(
+        reader: &mut dyn BufRead,
+        params: &Self::Params,
+        parts: &[Part],
+    ) -> Result<Vec<Answer>, StreamError>;
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    /// A syntax error, with the line it is on since the input is not kept.
+    Parse {
+        error: ParseError,
+        line: String,
+    },
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(source) => write!(f, "cannot read input: {source}"),
+            StreamError::Parse { error, .. } => error.fmt(f),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(source) => Some(source),
+            StreamError::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(source: io::Error) -> Self {
+        StreamError::Io(source)
+    }
+}
+
+/// Calls `line` with each line of `reader` that is not blank, trimmed, along with a source to
+/// report errors in it. The lines are read into the same buffer, so that only one is ever in
+/// memory, and an error is located at its line of the whole input.
+pub fn for_each_line(
+    problem: u8,
+    reader: &mut dyn BufRead,
+    mut line: impl FnMut(&Source, &str) -> Result<(), ParseError>,
+) -> Result<(), StreamError> {
+    let mut buffer = vec![];
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+        let text = std::str::from_utf8(&buffer)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let source = Source::new(problem, text);
+        let Some(trimmed) = source.lines(text).next() else {
+            continue;
+        };
+        line(&source, trimmed).map_err(|error| StreamError::Parse {
+            error: ParseError {
+                line: number,
+                ..error
+            },
+            line: text.trim_end_matches(['\n', '\r']).to_owned(),
+        })?;
+    }
+}
+
+/// Calls `chunk` with the successive chunks of bytes of `reader`, as buffered.
+pub fn for_each_chunk(
+    reader: &mut dyn BufRead,
+    mut chunk: impl FnMut(&[u8]),
+) -> Result<(), StreamError> {
+    loop {
+        let buffer = match reader.fill_buf() {
+            Ok([]) => return Ok(()),
+            Ok(buffer) => buffer,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err.into()),
+        };
+        chunk(buffer);
+        let len = buffer.len();
+        reader.consume(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::PROBLEMS;
+
+    #[test]
+    fn streamed_answers_are_the_parsed_ones() {
+        for problem in PROBLEMS.iter().filter(|problem| problem.can_stream()) {
+            let params = problem.default_params();
+            for seed in 0..5 {
+                let input = problem.generate(seed, 20);
+                let answers = |solved: Vec<crate::registry::Solved>| -> Vec<_> {
+                    solved.into_iter().map(|solved| solved.answer).collect()
+                };
+                let parsed = problem.solve(&input, &params, &Part::ALL).unwrap();
+                // A small buffer, for the lines and runs of characters to span several chunks.
+                let mut reader = io::BufReader::with_capacity(7, input.as_bytes());
+                let streamed = problem.stream(&mut reader, &params, &Part::ALL).unwrap();
+                assert_eq!(answers(streamed), answers(parsed), "problem {}", problem.id);
+            }
+        }
+    }
+
+    #[test]
+    fn errors_are_located_in_the_whole_input() {
+        let mut lines = vec![];
+        let result = for_each_line(4, &mut "AB\n\n  CxD\r\nE\n".as_bytes(), |source, line| {
+            lines.push(line.to_owned());
+            match line.find('x') {
+                Some(i) => Err(source.error(&line[i..=i], "a letter")),
+                None => Ok(()),
+            }
+        });
+        assert_eq!(lines, ["AB", "CxD"]);
+        let Err(StreamError::Parse { error, line }) = result else {
+            panic!("the line should be rejected");
+        };
+        assert_eq!((error.line, error.column), (3, 4));
+        assert_eq!(line, "  CxD");
+    }
+}
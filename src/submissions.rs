@@ -0,0 +1,292 @@
+//! The answers submitted to the website, kept so that a rejected answer is not submitted twice
+//! and submissions are spaced out.
+//!
+//! The history is a tab-separated file with one attempt per line: the time in seconds since the
+//! Unix epoch, the problem, the part, the judgement and the answer, last since it is free text.
+
+use crate::{Part, input};
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The time to wait after an attempt before the next one.
+pub const COOLDOWN_SECS: u64 = 60;
+
+/// What the website made of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Judgement {
+    Correct,
+    Incorrect,
+    /// The reply matched neither, e.g. a notice to wait before submitting again.
+    Unclear,
+}
+
+impl Judgement {
+    /// Reads the reply of the website to a submission.
+    pub fn of_reply(reply: &str) -> Self {
+        let reply = reply.to_lowercase();
+        if reply.contains("incorrect") || reply.contains("wrong") {
+            Judgement::Incorrect
+        } else if reply.contains("correct") {
+            Judgement::Correct
+        } else {
+            Judgement::Unclear
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Judgement::Correct => "correct",
+            Judgement::Incorrect => "incorrect",
+            Judgement::Unclear => "unclear",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [Judgement::Correct, Judgement::Incorrect, Judgement::Unclear]
+            .into_iter()
+            .find(|judgement| judgement.name() == name)
+    }
+}
+
+impl Display for Judgement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub problem: u8,
+    pub part: Part,
+    pub answer: String,
+    pub judgement: Judgement,
+}
+
+impl Attempt {
+    fn line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\n",
+            self.time, self.problem, self.part, self.judgement, self.answer
+        )
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(5, '\t');
+        Some(Self {
+            time: fields.next()?.parse().ok()?,
+            problem: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            judgement: Judgement::from_name(fields.next()?)?,
+            answer: fields.next()?.to_owned(),
+        })
+    }
+}
+
+/// Why an answer should not be submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    Solved { answer: String },
+    Rejected,
+    Cooldown { wait: u64 },
+}
+
+/// A history file that could not be read or written, or with a line that is not an attempt.
+#[derive(Debug)]
+pub enum HistoryError {
+    Io { path: PathBuf, source: io::Error },
+    Invalid { path: PathBuf, line: usize },
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            HistoryError::Invalid { path, line } => {
+                write!(f, "{}:{line}: invalid submission record", path.display())
+            }
+        }
+    }
+}
+
+impl Error for HistoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HistoryError::Io { source, .. } => Some(source),
+            HistoryError::Invalid { .. } => None,
+        }
+    }
+}
+
+pub fn default_path(set: Option<&str>) -> PathBuf {
+    input::set_dir(input::data_dir(), set).join("submissions.tsv")
+}
+
+/// The current time, in seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// The attempts recorded in a history file, oldest first.
+#[derive(Debug, Default)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Reads the history at `path`, which is empty when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, HistoryError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => {
+                return Err(HistoryError::Io {
+                    path: path.to_owned(),
+                    source,
+                });
+            }
+        };
+        let attempts = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                Attempt::parse(line).ok_or_else(|| HistoryError::Invalid {
+                    path: path.to_owned(),
+                    line: index + 1,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            path: path.to_owned(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Whether `answer` may be submitted to `part` of `problem` at `now`.
+    pub fn check(&self, problem: u8, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        let of_part = || {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.problem == problem && attempt.part == part)
+        };
+        if let Some(solved) = of_part().find(|attempt| attempt.judgement == Judgement::Correct) {
+            return Err(Refusal::Solved {
+                answer: solved.answer.clone(),
+            });
+        }
+        if of_part()
+            .any(|attempt| attempt.judgement == Judgement::Incorrect && attempt.answer == answer)
+        {
+            return Err(Refusal::Rejected);
+        }
+        match self.attempts.iter().map(|attempt| attempt.time).max() {
+            Some(last) if now < last + COOLDOWN_SECS => Err(Refusal::Cooldown {
+                wait: last + COOLDOWN_SECS - now,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Appends `attempt` to the history file, creating it and its directory when needed.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), HistoryError> {
+        let io_error = |source| HistoryError::Io {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(attempt.line().as_bytes()))
+            .map_err(io_error)?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replies_are_judged() {
+        assert_eq!(
+            Judgement::of_reply("Your answer is correct!"),
+            Judgement::Correct
+        );
+        assert_eq!(
+            Judgement::of_reply("That's the wrong answer."),
+            Judgement::Incorrect
+        );
+        assert_eq!(Judgement::of_reply("Incorrect"), Judgement::Incorrect);
+        assert_eq!(
+            Judgement::of_reply("Please wait before submitting again"),
+            Judgement::Unclear
+        );
+    }
+
+    #[test]
+    fn attempts_are_recorded_and_checked() {
+        let path = std::env::temp_dir().join(format!("codyssi-history-{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut history = History::load(&path).unwrap();
+        assert_eq!(history.check(3, Part::Two, "42", 1000), Ok(()));
+
+        let attempt = |time, answer: &str, judgement| Attempt {
+            time,
+            problem: 3,
+            part: Part::Two,
+            answer: answer.to_owned(),
+            judgement,
+        };
+        history
+            .record(attempt(1000, "41\tx", Judgement::Incorrect))
+            .unwrap();
+        assert_eq!(
+            history.check(3, Part::Two, "42", 1010),
+            Err(Refusal::Cooldown { wait: 50 })
+        );
+        assert_eq!(
+            history.check(3, Part::Two, "41\tx", 2000),
+            Err(Refusal::Rejected)
+        );
+        assert_eq!(history.check(3, Part::Two, "42", 1060), Ok(()));
+
+        history
+            .record(attempt(1060, "42", Judgement::Correct))
+            .unwrap();
+        assert_eq!(
+            history.check(3, Part::Two, "43", 2000),
+            Err(Refusal::Solved {
+                answer: "42".to_owned()
+            })
+        );
+        assert_eq!(history.check(3, Part::Three, "43", 2000), Ok(()));
+
+        let reloaded = History::load(&path).unwrap();
+        assert_eq!(reloaded.attempts(), history.attempts());
+        fs::write(&path, "1000\t3\t2\tcorrect\t42\nnot an attempt\n").unwrap();
+        assert_eq!(
+            History::load(&path).unwrap_err().to_string(),
+            format!("{}:2: invalid submission record", path.display())
+        );
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,276 @@
+//! A reader for the small subset of TOML used by the local configuration files:
+//! `[table]` headers, `key = value` pairs with integer or basic string values, and comments.
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Integer(i128),
+    String(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Integer(value) => value.fmt(f),
+            Value::String(value) => value.fmt(f),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TomlError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for TomlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for TomlError {}
+
+/// A configuration file that could not be read, or whose contents were rejected.
+#[derive(Debug)]
+pub enum FileError {
+    Read { path: PathBuf, source: io::Error },
+    Syntax { path: PathBuf, source: TomlError },
+}
+
+impl Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileError::Read { path, source } => {
+                write!(f, "cannot read {}: {source}", path.display())
+            }
+            FileError::Syntax { path, source } => {
+                write!(f, "{}:{}: {}", path.display(), source.line, source.message)
+            }
+        }
+    }
+}
+
+impl Error for FileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FileError::Read { source, .. } => Some(source),
+            FileError::Syntax { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Reads the file at `path` and hands its contents to `parse`, attaching the path to any error.
+pub fn read_file<T>(
+    path: &Path,
+    parse: impl FnOnce(&str) -> Result<T, TomlError>,
+) -> Result<T, FileError> {
+    let text = fs::read_to_string(path).map_err(|source| FileError::Read {
+        path: path.to_owned(),
+        source,
+    })?;
+    parse(&text).map_err(|source| FileError::Syntax {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+pub type Table = BTreeMap<String, Value>;
+
+/// The tables of a document, by name. Keys before the first header go in the `""` table.
+pub type Document = BTreeMap<String, Table>;
+
+pub fn parse(text: &str) -> Result<Document, TomlError> {
+    let mut document = Document::new();
+    let mut current = String::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let error = |message: &str| TomlError {
+            line: index + 1,
+            message: message.to_owned(),
+        };
+
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .ok_or_else(|| error("unterminated table header"))?
+                .trim();
+            if !is_bare_key(name) {
+                return Err(error("invalid table name"));
+            }
+            if document.contains_key(name) {
+                return Err(error("duplicate table"));
+            }
+            document.insert(name.to_owned(), Table::new());
+            current = name.to_owned();
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `key = value`"))?;
+        let key = key.trim();
+        if !is_bare_key(key) {
+            return Err(error("invalid key"));
+        }
+        let value = parse_value(value.trim()).map_err(|message| error(&message))?;
+        let table = document.entry(current.clone()).or_default();
+        if table.insert(key.to_owned(), value).is_some() {
+            return Err(error("duplicate key"));
+        }
+    }
+
+    Ok(document)
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_value(text: &str) -> Result<Value, String> {
+    if let Some(quoted) = text.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = quoted.chars();
+        loop {
+            match chars.next() {
+                None => return Err("unterminated string".to_owned()),
+                Some('"') => break,
+                Some('\\') => match chars.next() {
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    _ => return Err("unsupported escape sequence".to_owned()),
+                },
+                Some(c) => value.push(c),
+            }
+        }
+        if chars.as_str().trim().is_empty() {
+            Ok(Value::String(value))
+        } else {
+            Err("unexpected characters after string".to_owned())
+        }
+    } else {
+        text.replace('_', "")
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("expected an integer or a string, found `{text}`"))
+    }
+}
+
+/// Writes `value` so that [`parse`] reads it back.
+pub fn format_value(value: &Value) -> String {
+    match value {
+        Value::Integer(value) => value.to_string(),
+        Value::String(value) => format!(
+            "\"{}\"",
+            value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n")
+                .replace('\t', "\\t")
+        ),
+    }
+}
+
+/// The first line of `text` that mentions `word`, to point at an entry rejected after parsing.
+pub fn line_of(text: &str, word: &str) -> usize {
+    text.lines()
+        .position(|line| line.contains(word))
+        .map_or(1, |index| index + 1)
+}
+
+/// Writes `document` so that [`parse`] reads it back, with the top-level keys first.
+pub fn format(document: &Document) -> String {
+    let mut text = String::new();
+    for (name, table) in document {
+        if !name.is_empty() {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&format!("[{name}]\n"));
+        }
+        for (key, value) in table {
+            text.push_str(&format!("{key} = {}\n", format_value(value)));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_and_values() {
+        let document = parse(
+            "top = 1\n\
+             \n\
+             [problem15] # comment\n\
+             part1 = 9_592_632\n\
+             part2 = \"a-#b \\\"c\\\"\"  # trailing\n",
+        )
+        .unwrap();
+        assert_eq!(document[""]["top"], Value::Integer(1));
+        assert_eq!(document["problem15"]["part1"], Value::Integer(9592632));
+        assert_eq!(
+            document["problem15"]["part2"],
+            Value::String("a-#b \"c\"".to_owned())
+        );
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let err = parse("[a]\nx = 1\nx = 2\n").unwrap_err();
+        assert_eq!(err.line, 3);
+        let err = parse("[a]\ny = yes\n").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn formatted_values_round_trip() {
+        for value in [
+            Value::Integer(-12),
+            Value::String("S1_0-\"x\"\\y".to_owned()),
+        ] {
+            let document = parse(&format!("v = {}", format_value(&value))).unwrap();
+            assert_eq!(document[""]["v"], value);
+        }
+    }
+
+    #[test]
+    fn formatted_documents_round_trip() {
+        let document = parse("top = 1\n[b]\ny = \"2\"\n[a]\nx = 3\n").unwrap();
+        assert_eq!(parse(&format(&document)).unwrap(), document);
+    }
+}
@@ -0,0 +1,103 @@
+//! Opt-in tracing of the intermediate states of the solvers, to see where a wrong answer goes
+//! astray.
+//!
+//! Solvers [`emit`] events of some kind, with named fields. Nothing is recorded, nor even
+//! formatted, unless the solving runs inside [`traced`], on the same thread.
+
+use std::cell::RefCell;
+
+/// One step of a solver, e.g. an instruction applied to a grid.
+pub struct Event<'a> {
+    pub kind: &'static str,
+    pub fields: &'a [(&'static str, String)],
+}
+
+/// Which kinds of events to record.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Filter {
+    #[default]
+    All,
+    Kinds(Vec<String>),
+}
+
+impl Filter {
+    pub fn accepts(&self, kind: &str) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Kinds(kinds) => kinds.iter().any(|accepted| accepted == kind),
+        }
+    }
+}
+
+struct Tracer {
+    filter: Filter,
+    sink: Box<dyn FnMut(&Event)>,
+}
+
+thread_local! {
+    static TRACER: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, passing the events it emits on this thread to `sink` when `filter` accepts them.
+pub fn traced<T>(filter: Filter, sink: impl FnMut(&Event) + 'static, f: impl FnOnce() -> T) -> T {
+    /// Stops the tracing when `f` returns or panics.
+    struct Stop;
+
+    impl Drop for Stop {
+        fn drop(&mut self) {
+            TRACER.set(None);
+        }
+    }
+
+    TRACER.set(Some(Tracer {
+        filter,
+        sink: Box::new(sink),
+    }));
+    let _stop = Stop;
+    f()
+}
+
+/// Records an event of `kind` when it is traced, with the fields given by `fields`, which is
+/// only called then.
+pub fn emit(kind: &'static str, fields: impl FnOnce() -> Vec<(&'static str, String)>) {
+    TRACER.with_borrow_mut(|tracer| {
+        if let Some(tracer) = tracer
+            && tracer.filter.accepts(kind)
+        {
+            (tracer.sink)(&Event {
+                kind,
+                fields: &fields(),
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn only_traced_kinds_are_recorded() {
+        let recorded = Rc::new(RefCell::new(vec![]));
+        let sink = {
+            let recorded = Rc::clone(&recorded);
+            move |event: &Event| {
+                recorded
+                    .borrow_mut()
+                    .push(format!("{} {:?}", event.kind, event.fields))
+            }
+        };
+        let filter = Filter::Kinds(vec!["step".to_owned()]);
+        let answer = traced(filter, sink, || {
+            emit("step", || vec![("n", 1.to_string())]);
+            emit("other", || {
+                panic!("the fields of ignored events are not computed")
+            });
+            42
+        });
+        emit("step", || panic!("nothing is traced any more"));
+        assert_eq!(answer, 42);
+        assert_eq!(*recorded.borrow(), [r#"step [("n", "1")]"#]);
+    }
+}